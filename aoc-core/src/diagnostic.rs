@@ -0,0 +1,101 @@
+use std::{
+    fmt::{self, Display},
+    path::PathBuf,
+};
+
+use aoc_parse::ParseError;
+use stable_eyre::eyre::Report;
+
+/// An error located on a line of the puzzle input, rendered with the offending line and a caret
+/// under the column at fault.
+///
+/// ```text
+/// Expected ':' after Game declaration
+///  --> day2/1_sample.txt:2:8
+///   |
+/// 2 | Game 2 3 blue
+///   |        ^ expected ':', found '3'
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    file: Option<PathBuf>,
+    line: usize,
+    column: Option<usize>,
+    source_line: String,
+    message: String,
+    label: Option<String>,
+}
+
+impl Diagnostic {
+    /// Locates `error`, raised while parsing the line at `line_index` (counting from 0), within
+    /// the input. The column is taken from the first [`ParseError`] in the error's chain, if any.
+    pub fn new(error: &Report, line_index: usize, source_line: &str) -> Self {
+        let parse_error = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ParseError>());
+        let message = error
+            .chain()
+            .take_while(|cause| !cause.is::<ParseError>())
+            .map(|cause| cause.to_string())
+            .collect::<Vec<_>>()
+            .join(": ");
+
+        Diagnostic {
+            file: None,
+            line: line_index + 1,
+            column: parse_error.map(|e| source_line[..e.pos()].chars().count() + 1),
+            source_line: source_line.to_string(),
+            message: if message.is_empty() {
+                parse_error.map(|e| e.to_string()).unwrap_or_default()
+            } else {
+                message
+            },
+            label: parse_error.map(|e| e.label()),
+        }
+    }
+
+    /// Names the file the input was read from.
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error, counting from 1, if it could be pinned down.
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}", self.message)?;
+        write!(f, "{gutter}--> ")?;
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}", self.line)?;
+        if let Some(column) = self.column {
+            write!(f, ":{column}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "{gutter} |")?;
+        write!(f, "{} | {}", self.line, self.source_line)?;
+        if let Some(column) = self.column {
+            write!(f, "\n{gutter} | {}^", " ".repeat(column - 1))?;
+            if let Some(label) = &self.label {
+                write!(f, " {label}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Diagnostic {}
@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use stable_eyre::eyre::Report;
+
+mod diagnostic;
+mod table;
+
+pub use diagnostic::Diagnostic;
+pub use table::table;
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+/// A solver for a single day of the calendar.
+///
+/// Every day is a library crate exposing one type implementing this trait, which the `aoc` runner
+/// dispatches to based on the requested day. The input is parsed once and then shared by both
+/// parts, so a caller may ask for either part on its own.
+pub trait Solution {
+    /// The day of the calendar this solution solves.
+    const DAY: u8;
+
+    /// The parsed puzzle input.
+    type Input;
+    /// The answer produced by either part.
+    type Answer: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Report>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer, Report>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer, Report>;
+
+    /// Parses `input`, skipping malformed records instead of stopping at the first one.
+    ///
+    /// Returns the input made of the well-formed records alongside a diagnostic for every record
+    /// that was skipped. Days which cannot skip records parse the whole input as usual.
+    fn parse_recovering(&self, input: &str) -> Result<(Self::Input, Vec<Diagnostic>), Report> {
+        self.parse(input).map(|input| (input, vec![]))
+    }
+
+    /// Solves the requested part of the puzzle.
+    fn solve(&self, input: &Self::Input, part: Part) -> Result<Self::Answer, Report> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+/// Parses every line of `input` with `parse_line`, stopping at the first malformed line.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, Report>,
+) -> Result<Vec<T>, Report> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|e| Diagnostic::new(&e, index, line).into()))
+        .collect()
+}
+
+/// Parses every line of `input` with `parse_line`, skipping malformed lines and collecting a
+/// diagnostic for each of them.
+pub fn parse_lines_recovering<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, Report>,
+) -> (Vec<T>, Vec<Diagnostic>) {
+    let mut records = vec![];
+    let mut errors = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        match parse_line(line) {
+            Ok(record) => records.push(record),
+            Err(e) => errors.push(Diagnostic::new(&e, index, line)),
+        }
+    }
+
+    (records, errors)
+}
@@ -0,0 +1,16 @@
+use aoc_core::table;
+
+#[test]
+fn columns_fit_their_widest_cell() {
+    let rows = vec![
+        ["1".to_string(), "grün".to_string(), "x".to_string()],
+        ["10".to_string(), "-".to_string(), String::new()],
+    ];
+
+    assert_eq!(
+        table(["N", "Colour", "Note"], rows),
+        "N   Colour  Note\n\
+         1   grün    x\n\
+         10  -\n"
+    );
+}
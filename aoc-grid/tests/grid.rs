@@ -0,0 +1,72 @@
+use aoc_grid::{Grid, GridError, Pos};
+
+const SAMPLE: &str = "ab1\ncd2\nef3\n";
+
+#[test]
+fn parses_rows_of_text() {
+    let grid: Grid<char> = SAMPLE.parse().unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid[Pos::new(1, 2)], '2');
+    assert_eq!(grid.get(Pos::new(3, 0)), None);
+    assert_eq!(grid.row(2), Some(&['e', 'f', '3'][..]));
+    assert_eq!(grid.column(2).collect::<String>(), "123");
+}
+
+#[test]
+fn rejects_ragged_lines() {
+    assert_eq!(
+        "abc\nde\n".parse::<Grid<char>>(),
+        Err(GridError::Ragged {
+            line: 2,
+            expected: 3,
+            found: 2
+        })
+    );
+}
+
+#[test]
+fn ignores_trailing_blank_lines() {
+    let grid: Grid<char> = "ab\ncd\n\r\n\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+
+    let grid: Grid<char> = "\n\n".parse().unwrap();
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid: Grid<char> = SAMPLE.parse().unwrap();
+
+    let corner: Vec<Pos> = grid.neighbours8(Pos::new(0, 0)).collect();
+    assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]);
+    assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbours4(Pos::new(2, 2)).count(), 2);
+}
+
+#[test]
+fn windows_cover_consecutive_rows() {
+    let grid: Grid<char> = SAMPLE.parse().unwrap();
+    let windows: Vec<Vec<&[char]>> = grid.row_windows(2).collect();
+
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[1], [&['c', 'd', '2'][..], &['e', 'f', '3'][..]]);
+    assert_eq!(grid.row_windows(4).count(), 0);
+}
+
+#[test]
+fn empty_text_is_an_empty_grid() {
+    let grid: Grid<char> = "".parse().unwrap();
+
+    assert!(grid.is_empty());
+    assert_eq!(grid.positions().count(), 0);
+    assert_eq!(grid.to_string(), "");
+}
+
+#[test]
+fn prints_with_rulers_in_alternate_form() {
+    let grid: Grid<char> = SAMPLE.parse().unwrap();
+
+    assert_eq!(grid.to_string(), SAMPLE);
+    assert_eq!(format!("{grid:#}"), "  123\n1 ab1\n2 cd2\n3 ef3\n");
+}
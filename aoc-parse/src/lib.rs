@@ -0,0 +1,255 @@
+//! Tokenizer combinators shared by the line-oriented puzzle inputs.
+//!
+//! Every combinator works on a [`Cursor`] and either consumes what it recognised or, on failure,
+//! leaves the cursor untouched, so alternatives can be tried one after another.
+
+use std::{num::ParseIntError, str::FromStr};
+
+/// A position within the input being tokenized.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Cursor { input, pos: 0 }
+    }
+
+    /// The byte offset of the cursor from the start of the input.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// The part of the input that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let consumed = &self.rest()[..len];
+        self.pos += len;
+        consumed
+    }
+}
+
+/// Why a combinator failed. The messages leave out where it failed, which callers report from
+/// [`ParseError::pos`] in whatever unit suits them.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    #[error("Expected '{expected}', found {}.", describe(*.found))]
+    ExpectedKeyword {
+        expected: String,
+        pos: usize,
+        found: Option<char>,
+    },
+    #[error("Expected a number, found {}.", describe(*.found))]
+    ExpectedNumber { pos: usize, found: Option<char> },
+    #[error("Expected a word, found {}.", describe(*.found))]
+    ExpectedWord { pos: usize, found: Option<char> },
+    #[error("The number is not valid: {source}")]
+    InvalidNumber {
+        pos: usize,
+        #[source]
+        source: ParseIntError,
+    },
+    #[error("Expected one of {expected:?}, found {}.", describe(*.found))]
+    ExpectedSeparator {
+        expected: Vec<char>,
+        pos: usize,
+        found: Option<char>,
+    },
+    #[error("Expected the input to end, found {}.", describe(Some(*.found)))]
+    TrailingInput { pos: usize, found: char },
+}
+
+impl ParseError {
+    /// The byte offset at which the error occurred.
+    pub fn pos(&self) -> usize {
+        match self {
+            ParseError::ExpectedKeyword { pos, .. }
+            | ParseError::ExpectedNumber { pos, .. }
+            | ParseError::ExpectedWord { pos, .. }
+            | ParseError::InvalidNumber { pos, .. }
+            | ParseError::ExpectedSeparator { pos, .. }
+            | ParseError::TrailingInput { pos, .. } => *pos,
+        }
+    }
+
+    /// The character found where the error occurred, if the input did not end there.
+    pub fn found(&self) -> Option<char> {
+        match self {
+            ParseError::ExpectedKeyword { found, .. }
+            | ParseError::ExpectedNumber { found, .. }
+            | ParseError::ExpectedWord { found, .. }
+            | ParseError::ExpectedSeparator { found, .. } => *found,
+            ParseError::InvalidNumber { .. } => None,
+            ParseError::TrailingInput { found, .. } => Some(*found),
+        }
+    }
+
+    /// A short description of the error, meant to be shown next to the offending column.
+    pub fn label(&self) -> String {
+        let expected = match self {
+            ParseError::ExpectedKeyword { expected, .. } => format!("'{expected}'"),
+            ParseError::ExpectedNumber { .. } => "a number".to_string(),
+            ParseError::ExpectedWord { .. } => "a word".to_string(),
+            ParseError::InvalidNumber { source, .. } => return format!("invalid number: {source}"),
+            ParseError::ExpectedSeparator { expected, .. } => format!("one of {expected:?}"),
+            ParseError::TrailingInput { .. } => "the end of the input".to_string(),
+        };
+
+        format!("expected {expected}, found {}", describe(self.found()))
+    }
+}
+
+fn describe(found: Option<char>) -> String {
+    match found {
+        Some(c) => format!("'{}'", c.escape_debug()),
+        None => "the end of the input".to_string(),
+    }
+}
+
+/// Consumes the literal `keyword`.
+pub fn keyword<'a>(input: &mut Cursor<'a>, keyword: &str) -> Result<&'a str, ParseError> {
+    if input.rest().starts_with(keyword) {
+        Ok(input.advance(keyword.len()))
+    } else {
+        Err(ParseError::ExpectedKeyword {
+            expected: keyword.to_string(),
+            pos: input.pos(),
+            found: input.peek(),
+        })
+    }
+}
+
+fn digits<'a>(input: &Cursor<'a>, start: usize) -> &'a str {
+    let rest = &input.rest()[start..];
+    let len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    &rest[..len]
+}
+
+fn number<T: FromStr<Err = ParseIntError>>(
+    input: &mut Cursor<'_>,
+    sign_len: usize,
+) -> Result<T, ParseError> {
+    let digits = digits(input, sign_len);
+    if digits.is_empty() {
+        return Err(ParseError::ExpectedNumber {
+            pos: input.pos(),
+            found: input.peek(),
+        });
+    }
+
+    let pos = input.pos();
+    let number = input.rest()[..sign_len + digits.len()]
+        .parse()
+        .map_err(|source| ParseError::InvalidNumber { pos, source })?;
+    input.advance(sign_len + digits.len());
+
+    Ok(number)
+}
+
+/// Consumes a run of ASCII digits as an unsigned number.
+pub fn unsigned<T: FromStr<Err = ParseIntError>>(input: &mut Cursor<'_>) -> Result<T, ParseError> {
+    number(input, 0)
+}
+
+/// Consumes a run of ASCII digits, optionally preceded by a '+' or '-' sign.
+pub fn signed<T: FromStr<Err = ParseIntError>>(input: &mut Cursor<'_>) -> Result<T, ParseError> {
+    let sign_len = match input.peek() {
+        Some('+' | '-') => 1,
+        _ => 0,
+    };
+
+    number(input, sign_len)
+}
+
+/// Consumes a run of alphabetic characters.
+pub fn word<'a>(input: &mut Cursor<'a>) -> Result<&'a str, ParseError> {
+    let rest = input.rest();
+    let len = rest
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(rest.len());
+
+    if len == 0 {
+        Err(ParseError::ExpectedWord {
+            pos: input.pos(),
+            found: input.peek(),
+        })
+    } else {
+        Ok(input.advance(len))
+    }
+}
+
+/// Consumes whichever of the `separators` comes next, returning it.
+pub fn separator(input: &mut Cursor<'_>, separators: &[char]) -> Result<char, ParseError> {
+    match input.peek() {
+        Some(c) if separators.contains(&c) => {
+            input.advance(c.len_utf8());
+            Ok(c)
+        }
+        found => Err(ParseError::ExpectedSeparator {
+            expected: separators.to_vec(),
+            pos: input.pos(),
+            found,
+        }),
+    }
+}
+
+/// Consumes any whitespace, including none at all.
+pub fn skip_whitespace(input: &mut Cursor<'_>) {
+    let rest = input.rest();
+    let len = rest.len() - rest.trim_start().len();
+    input.advance(len);
+}
+
+/// Checks that the whole input has been consumed.
+pub fn end(input: &mut Cursor<'_>) -> Result<(), ParseError> {
+    match input.peek() {
+        None => Ok(()),
+        Some(found) => Err(ParseError::TrailingInput {
+            pos: input.pos(),
+            found,
+        }),
+    }
+}
+
+/// Consumes one or more `item`s delimited by `separator`.
+///
+/// The list ends at the first separator which is not followed by an item; that separator is left
+/// unconsumed.
+pub fn list_of<'a, T, S>(
+    input: &mut Cursor<'a>,
+    mut item: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    mut separator: impl FnMut(&mut Cursor<'a>) -> Result<S, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut items = vec![item(input)?];
+
+    loop {
+        let mut lookahead = input.clone();
+        if separator(&mut lookahead).is_err() {
+            break;
+        }
+        match item(&mut lookahead) {
+            Ok(next) => {
+                items.push(next);
+                *input = lookahead;
+            }
+            Err(_) => break,
+        }
+    }
+
+    Ok(items)
+}
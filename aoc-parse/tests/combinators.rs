@@ -0,0 +1,180 @@
+use aoc_parse::{
+    end, keyword, list_of, separator, signed, skip_whitespace, unsigned, word, Cursor, ParseError,
+};
+
+#[test]
+fn keyword_consumes_the_literal() {
+    let mut input = Cursor::new("Game 1");
+
+    assert_eq!(keyword(&mut input, "Game"), Ok("Game"));
+    assert_eq!(input.rest(), " 1");
+}
+
+#[test]
+fn keyword_leaves_other_input_untouched() {
+    let mut input = Cursor::new("Gam 1");
+
+    assert_eq!(
+        keyword(&mut input, "Game"),
+        Err(ParseError::ExpectedKeyword {
+            expected: "Game".to_string(),
+            pos: 0,
+            found: Some('G'),
+        })
+    );
+    assert_eq!(input.pos(), 0);
+}
+
+#[test]
+fn unsigned_reads_digits_only() {
+    let mut input = Cursor::new("42 red");
+    assert_eq!(unsigned::<u32>(&mut input), Ok(42));
+    assert_eq!(input.rest(), " red");
+
+    let mut input = Cursor::new("-42");
+    assert_eq!(
+        unsigned::<u32>(&mut input),
+        Err(ParseError::ExpectedNumber {
+            pos: 0,
+            found: Some('-'),
+        })
+    );
+    assert_eq!(input.pos(), 0);
+}
+
+#[test]
+fn numbers_too_large_for_their_type_are_invalid() {
+    let mut input = Cursor::new("x 4294967296");
+    keyword(&mut input, "x ").unwrap();
+
+    let error = unsigned::<u32>(&mut input).unwrap_err();
+    assert!(matches!(error, ParseError::InvalidNumber { pos: 2, .. }));
+    assert_eq!(error.found(), None);
+    assert_eq!(input.rest(), "4294967296");
+
+    assert_eq!(unsigned::<u64>(&mut input), Ok(4_294_967_296));
+    assert!(input.is_empty());
+}
+
+#[test]
+fn signed_reads_an_optional_sign() {
+    for (text, number) in [("-17", -17), ("+17", 17), ("17", 17)] {
+        let mut input = Cursor::new(text);
+        assert_eq!(signed::<i32>(&mut input), Ok(number));
+        assert!(input.is_empty());
+    }
+}
+
+#[test]
+fn signed_needs_digits_after_the_sign() {
+    let mut input = Cursor::new("-x");
+
+    assert_eq!(
+        signed::<i32>(&mut input),
+        Err(ParseError::ExpectedNumber {
+            pos: 0,
+            found: Some('-'),
+        })
+    );
+    assert_eq!(input.pos(), 0);
+
+    let mut input = Cursor::new("-129");
+    assert!(matches!(
+        signed::<i8>(&mut input),
+        Err(ParseError::InvalidNumber { pos: 0, .. })
+    ));
+    assert_eq!(input.pos(), 0);
+}
+
+#[test]
+fn word_reads_alphabetic_characters() {
+    let mut input = Cursor::new("grün, blue");
+    assert_eq!(word(&mut input), Ok("grün"));
+    assert_eq!(input.rest(), ", blue");
+
+    assert_eq!(
+        word(&mut input),
+        Err(ParseError::ExpectedWord {
+            pos: "grün".len(),
+            found: Some(','),
+        })
+    );
+    assert_eq!(input.rest(), ", blue");
+}
+
+#[test]
+fn separator_returns_the_one_found() {
+    let mut input = Cursor::new(";,");
+
+    assert_eq!(separator(&mut input, &[',', ';']), Ok(';'));
+    assert_eq!(separator(&mut input, &[',', ';']), Ok(','));
+    assert_eq!(
+        separator(&mut input, &[',', ';']),
+        Err(ParseError::ExpectedSeparator {
+            expected: vec![',', ';'],
+            pos: 2,
+            found: None,
+        })
+    );
+}
+
+#[test]
+fn skip_whitespace_may_skip_nothing() {
+    let mut input = Cursor::new(" \t 1");
+    skip_whitespace(&mut input);
+    assert_eq!(input.rest(), "1");
+
+    skip_whitespace(&mut input);
+    assert_eq!(input.rest(), "1");
+}
+
+#[test]
+fn end_rejects_trailing_input() {
+    let mut input = Cursor::new("1 ");
+    unsigned::<u32>(&mut input).unwrap();
+
+    assert_eq!(
+        end(&mut input),
+        Err(ParseError::TrailingInput { pos: 1, found: ' ' })
+    );
+
+    skip_whitespace(&mut input);
+    assert_eq!(end(&mut input), Ok(()));
+}
+
+fn comma(input: &mut Cursor<'_>) -> Result<char, ParseError> {
+    separator(input, &[','])
+}
+
+#[test]
+fn list_of_reads_separated_items() {
+    let mut input = Cursor::new("1,2,3 end");
+
+    assert_eq!(
+        list_of(&mut input, unsigned::<u32>, comma),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(input.rest(), " end");
+}
+
+#[test]
+fn list_of_leaves_a_trailing_separator_unconsumed() {
+    let mut input = Cursor::new("1,2,x");
+
+    assert_eq!(list_of(&mut input, unsigned::<u32>, comma), Ok(vec![1, 2]));
+    assert_eq!(input.rest(), ",x");
+}
+
+#[test]
+fn list_of_needs_one_item() {
+    let mut input = Cursor::new(",1");
+
+    assert_eq!(
+        list_of(&mut input, unsigned::<u32>, comma),
+        Err(ParseError::ExpectedNumber {
+            pos: 0,
+            found: Some(','),
+        })
+    );
+    assert_eq!(input.pos(), 0);
+}
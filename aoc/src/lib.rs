@@ -0,0 +1,88 @@
+use aoc_core::{Diagnostic, Part, Solution};
+use stable_eyre::eyre::{eyre, Report};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AocError {
+    #[error("Day {0} has not been solved yet.")]
+    UnknownDay(u8),
+}
+
+/// How to treat malformed records in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Fail on the first malformed record.
+    Strict,
+    /// Skip malformed records and solve the puzzle over the well-formed ones.
+    KeepGoing,
+}
+
+/// The answers to the requested parts, in the order they were requested. A part failing to solve
+/// does not keep the other from being answered.
+#[derive(Debug)]
+pub struct Solved {
+    pub answers: Vec<Result<String, Report>>,
+    /// Diagnostics for the records skipped in [`ParseMode::KeepGoing`].
+    pub skipped: Vec<Diagnostic>,
+}
+
+/// A [`Solution`] with its input and answer types erased, so the day can be picked at runtime.
+pub trait DynSolution {
+    fn solve(&self, parts: &[Part], input: &str, mode: ParseMode) -> Result<Solved, Report>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn solve(&self, parts: &[Part], input: &str, mode: ParseMode) -> Result<Solved, Report> {
+        let (input, skipped) = parse(self, input, mode)?;
+        Ok(answer(self, parts, &input, skipped))
+    }
+}
+
+/// Parses `input` for `solution`, along with diagnostics for the records skipped in
+/// [`ParseMode::KeepGoing`].
+pub fn parse<S: Solution>(
+    solution: &S,
+    input: &str,
+    mode: ParseMode,
+) -> Result<(S::Input, Vec<Diagnostic>), Report> {
+    match mode {
+        ParseMode::Strict => Ok((solution.parse(input)?, vec![])),
+        ParseMode::KeepGoing => solution.parse_recovering(input),
+    }
+}
+
+/// Solves each of the requested `parts` over an input returned by [`parse`], which skipped the
+/// records of `skipped`.
+pub fn answer<S: Solution>(
+    solution: &S,
+    parts: &[Part],
+    input: &S::Input,
+    skipped: Vec<Diagnostic>,
+) -> Solved {
+    let answers = parts
+        .iter()
+        .map(|&part| Solution::solve(solution, input, part).map(|answer| answer.to_string()))
+        .collect();
+
+    Solved { answers, skipped }
+}
+
+/// The solution of the given day.
+pub fn solution(day: u8) -> Result<Box<dyn DynSolution>, Report> {
+    match day {
+        day1::Day1::DAY => Ok(Box::new(day1::Day1::default())),
+        day2::Day2::DAY => Ok(Box::new(day2::Day2::default())),
+        day3::Day3::DAY => Ok(Box::new(day3::Day3::default())),
+        day4::Day4::DAY => Ok(Box::new(day4::Day4::default())),
+        _ => Err(eyre!(AocError::UnknownDay(day))),
+    }
+}
+
+/// Parses `input` once and solves each of the requested `parts` of the given day, returning the
+/// answers in the same order. Fails if any of the parts fails.
+pub fn solve(day: u8, parts: &[Part], input: &str) -> Result<Vec<String>, Report> {
+    solution(day)?
+        .solve(parts, input, ParseMode::Strict)?
+        .answers
+        .into_iter()
+        .collect()
+}
@@ -0,0 +1,439 @@
+use std::{io::BufReader, path::PathBuf};
+
+use aoc::{DynSolution, ParseMode, Solved};
+use aoc_core::{Diagnostic, Part, Solution};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use stable_eyre::eyre::{bail, Context, Report};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day's puzzle for the given input file. Example: aoc run 3 day3/1_full.txt
+    Run(RunArgs),
+    /// Describe a day's input in more depth than the answers. Example: aoc report 3 day3/1_full.txt
+    Report(ReportArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// The day of the puzzle to solve.
+    day: u8,
+    /// The puzzle input.
+    input: PathBuf,
+    /// Only solve one part of the puzzle. Both parts are solved by default.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Skip malformed lines instead of stopping at the first one, solving the puzzle over the
+    /// remaining lines and listing every skipped line on stderr at the end. Skipped lines do not
+    /// fail the run.
+    #[arg(long)]
+    keep_going: bool,
+    #[command(flatten)]
+    day1: Day1Args,
+    #[command(flatten)]
+    day2: Day2Args,
+    #[command(flatten)]
+    day3: Day3Args,
+    #[command(flatten)]
+    day4: Day4Args,
+    /// Scan the schematic a few rows at a time instead of reading it whole, for inputs too large
+    /// to fit in memory.
+    #[arg(long, help_heading = "Day 3")]
+    stream: bool,
+}
+
+#[derive(Debug, Args)]
+struct ReportArgs {
+    /// The day of the puzzle to report on.
+    day: u8,
+    /// The puzzle input.
+    input: PathBuf,
+    /// How to print the report. Only day 3 reports come as CSV or JSON.
+    #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+    format: ReportFormat,
+    #[command(flatten)]
+    day3: Day3Args,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Day 1")]
+struct Day1Args {
+    /// The words spelling out digits: english, french, german, spanish, or a file with one
+    /// <word>=<digit> entry per line. Defaults to english.
+    #[arg(long, value_name = "NAME|FILE")]
+    lexicon: Option<String>,
+    /// Read ASCII digits only in both parts.
+    #[arg(long, conflicts_with_all = ["with_words", "per_part"])]
+    digits_only: bool,
+    /// Read spelled-out digits as well in both parts. This is the default.
+    #[arg(long, conflicts_with = "per_part")]
+    with_words: bool,
+    /// Read ASCII digits only in part 1 and spelled-out digits as well in part 2, as the puzzle
+    /// does, so both answers come from one input.
+    #[arg(long)]
+    per_part: bool,
+    /// How to read spelled-out digits overlapping each other, as in "eightwo": overlapping reads
+    /// both (82), greedy reads left to right without overlaps (88), and longest does the same but
+    /// prefers the longest of the words starting at the same place.
+    #[arg(long, value_enum, value_name = "POLICY", default_value_t = Overlap::Overlapping)]
+    overlap: Overlap,
+    /// Print how every line is decoded to stderr, as an aligned table or as JSON Lines.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table"
+    )]
+    trace: Option<TraceFormat>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TraceFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Overlap {
+    Overlapping,
+    Greedy,
+    Longest,
+}
+
+impl From<Overlap> for day1::OverlapPolicy {
+    fn from(overlap: Overlap) -> Self {
+        match overlap {
+            Overlap::Overlapping => day1::OverlapPolicy::Overlapping,
+            Overlap::Greedy => day1::OverlapPolicy::Greedy,
+            Overlap::Longest => day1::OverlapPolicy::Longest,
+        }
+    }
+}
+
+impl Day1Args {
+    fn solution(&self) -> Result<day1::Day1, Report> {
+        let lexicon = match &self.lexicon {
+            None => day1::DigitLexicon::default(),
+            Some(name) => match day1::DigitLexicon::builtin(name) {
+                Some(lexicon) => lexicon,
+                None => std::fs::read_to_string(name)
+                    .wrap_err_with(|| {
+                        format!(
+                            "'{name}' is neither a built-in lexicon ({}) nor a readable file",
+                            day1::DigitLexicon::BUILTIN.join(", ")
+                        )
+                    })?
+                    .parse()
+                    .wrap_err_with(|| format!("Failed to parse the lexicon in {name}"))?,
+            },
+        };
+
+        let day = day1::Day1::new(lexicon).with_overlap(self.overlap.into());
+        Ok(match (self.digits_only, self.per_part) {
+            (true, _) => day.with_mode(day1::DecodeMode::DigitsOnly),
+            (_, true) => day.with_mode(day1::DecodeMode::PerPart),
+            _ => day.with_mode(day1::DecodeMode::WithWords),
+        })
+    }
+
+    fn trace(&self, input: &str, parts: &[Part]) -> Result<(), Report> {
+        let Some(format) = self.trace else {
+            return Ok(());
+        };
+
+        let day = self.solution()?;
+        let lines = day.parse(input)?;
+        let traces: Vec<day1::LineTrace> = parts
+            .iter()
+            .flat_map(|&part| day.trace(&lines, part))
+            .collect();
+
+        match format {
+            TraceFormat::Table => eprint!("{}", day1::LineTrace::table(&traces)),
+            TraceFormat::Json => eprint!("{}", day1::LineTrace::json_lines(&traces)?),
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Day 2")]
+struct Day2Args {
+    /// The cubes in the bag, e.g. red=20,green=5,blue=9. Defaults to the bag from the puzzle.
+    #[arg(long, conflicts_with = "bag_file")]
+    bag: Option<day2::Bag>,
+    /// Read the cubes in the bag from a file, written as for --bag.
+    #[arg(long)]
+    bag_file: Option<PathBuf>,
+}
+
+impl Day2Args {
+    fn solution(&self) -> Result<day2::Day2, Report> {
+        let bag = match (&self.bag, &self.bag_file) {
+            (Some(bag), _) => bag.clone(),
+            (None, Some(path)) => std::fs::read_to_string(path)
+                .wrap_err_with(|| format!("Failed to read {}", path.display()))?
+                .parse()
+                .wrap_err_with(|| format!("Failed to parse the bag in {}", path.display()))?,
+            (None, None) => day2::Bag::default(),
+        };
+
+        Ok(day2::Day2 { bag })
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Day 3")]
+struct Day3Args {
+    /// The symbols that may be gears, e.g. '*#'. Defaults to '*'.
+    #[arg(long, value_name = "SYMBOLS")]
+    gear_symbols: Option<String>,
+    /// How many numbers must touch a gear: exactly N, MIN-MAX, or at least MIN with MIN-.
+    /// Defaults to 2.
+    #[arg(long, value_name = "N|MIN-MAX|MIN-")]
+    gear_numbers: Option<day3::NumberCount>,
+    /// How the numbers of a gear combine into its ratio.
+    #[arg(long, value_enum, value_name = "HOW", default_value_t = GearCombine::Product)]
+    gear_combine: GearCombine,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum GearCombine {
+    Product,
+    Sum,
+    Min,
+    Max,
+}
+
+impl From<GearCombine> for day3::Combine {
+    fn from(combine: GearCombine) -> Self {
+        match combine {
+            GearCombine::Product => day3::Combine::Product,
+            GearCombine::Sum => day3::Combine::Sum,
+            GearCombine::Min => day3::Combine::Min,
+            GearCombine::Max => day3::Combine::Max,
+        }
+    }
+}
+
+impl Day3Args {
+    fn solution(&self) -> day3::Day3 {
+        let defaults = day3::GearRules::default();
+        let rules = day3::GearRules {
+            symbols: match &self.gear_symbols {
+                Some(symbols) => symbols.chars().collect(),
+                None => defaults.symbols,
+            },
+            count: self.gear_numbers.unwrap_or(defaults.count),
+            combine: self.gear_combine.into(),
+        };
+
+        day3::Day3 { rules }
+    }
+
+    fn stream(&self, path: &PathBuf, parts: &[Part]) -> Result<(), Report> {
+        let file = std::fs::File::open(path)
+            .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+        let totals = day3::stream(BufReader::new(file), &self.solution().rules, |_| {})
+            .wrap_err_with(|| format!("Failed to scan {}", path.display()))?;
+
+        for part in parts {
+            let answer = match part {
+                Part::One => totals.part_number_sum,
+                Part::Two => totals.gear_ratio_sum,
+            };
+            println!("{part}: {answer}");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(next_help_heading = "Day 4")]
+struct Day4Args {
+    /// What to do about duplicate, missing or out-of-order card ids and numbers written twice on
+    /// a card: print a warning for each to stderr, or refuse to solve the puzzle.
+    #[arg(long, value_enum, value_name = "ACTION", default_value_t = InvalidCards::Warn)]
+    invalid_cards: InvalidCards,
+    /// Print to stderr, for every card, its matches, its points, how many copies of it are held
+    /// and which cards it wins copies of.
+    #[arg(long)]
+    explain: bool,
+    /// Write which cards win copies of which to FILE, as a Graphviz graph.
+    #[arg(long, value_name = "FILE")]
+    dot: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum InvalidCards {
+    Warn,
+    Deny,
+}
+
+impl Day4Args {
+    fn solution(&self) -> day4::Day4 {
+        let validation = match self.invalid_cards {
+            InvalidCards::Warn => day4::Validation::Warn,
+            InvalidCards::Deny => day4::Validation::Deny,
+        };
+
+        day4::Day4 { validation }
+    }
+
+    /// Solves the puzzle, printing the warnings and explanations asked for and writing the graph
+    /// of the cards once every card has been read.
+    fn solve(&self, parts: &[Part], input: &str, mode: ParseMode) -> Result<Solved, Report> {
+        let day = self.solution();
+        let (table, skipped) = aoc::parse(&day, input, mode)?;
+        self.inspect(&table)?;
+        Ok(aoc::answer(&day, parts, &table, skipped))
+    }
+
+    fn inspect(&self, table: &day4::CardTable) -> Result<(), Report> {
+        // Denied issues have already failed the parse.
+        if self.solution().validation == day4::Validation::Warn {
+            for issue in table.issues() {
+                eprintln!("Warning: {issue}");
+            }
+        }
+        if self.explain {
+            eprint!("{}", day4::CardExplanation::table(&table.explain()?));
+        }
+        if let Some(path) = &self.dot {
+            std::fs::write(path, table.dot())
+                .wrap_err_with(|| format!("Failed to write {}", path.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The solution of the requested day, configured by the day's options.
+fn solution(args: &RunArgs) -> Result<Box<dyn DynSolution>, Report> {
+    match args.day {
+        1 => Ok(Box::new(args.day1.solution()?)),
+        2 => Ok(Box::new(args.day2.solution()?)),
+        3 => Ok(Box::new(args.day3.solution())),
+        4 => Ok(Box::new(args.day4.solution())),
+        day => aoc::solution(day),
+    }
+}
+
+fn read_input(path: &PathBuf) -> Result<String, Report> {
+    std::fs::read_to_string(path).wrap_err_with(|| format!("Failed to read {}", path.display()))
+}
+
+/// Names the input file in errors located within it.
+fn locate(e: Report, path: &PathBuf) -> Report {
+    match e.downcast::<Diagnostic>() {
+        Ok(diagnostic) => diagnostic.with_file(path).into(),
+        Err(e) => e,
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), Report> {
+    let path = &args.input;
+    let parts = match args.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => vec![Part::One, Part::Two],
+    };
+
+    if args.day == 3 && args.stream {
+        return args.day3.stream(path, &parts);
+    }
+
+    let input = read_input(path)?;
+    let mode = if args.keep_going {
+        ParseMode::KeepGoing
+    } else {
+        ParseMode::Strict
+    };
+
+    if args.day == 1 {
+        args.day1.trace(&input, &parts)?;
+    }
+    let solved = match args.day {
+        4 => args.day4.solve(&parts, &input, mode),
+        _ => solution(&args)?.solve(&parts, &input, mode),
+    }
+    .map_err(|e| locate(e, path))?;
+
+    let mut failed = 0;
+    for (part, answer) in parts.iter().zip(solved.answers) {
+        match answer {
+            Ok(answer) => println!("{part}: {answer}"),
+            Err(e) => {
+                eprintln!("{part} failed: {e:#}");
+                failed += 1;
+            }
+        }
+    }
+
+    if !solved.skipped.is_empty() {
+        for diagnostic in &solved.skipped {
+            eprintln!("{}\n", diagnostic.clone().with_file(path));
+        }
+        eprintln!("Skipped {} malformed line(s).", solved.skipped.len());
+    }
+
+    if failed > 0 {
+        bail!("Failed to solve {failed} part(s).");
+    }
+
+    Ok(())
+}
+
+fn report(args: ReportArgs) -> Result<(), Report> {
+    let path = &args.input;
+    let input = read_input(path)?;
+
+    match args.day {
+        2 => {
+            if args.format != ReportFormat::Table {
+                bail!("The day 2 report only comes as a table.");
+            }
+            let day = day2::Day2::default();
+            let games = day.parse(&input).map_err(|e| locate(e, path))?;
+            print!("{}", day2::BagReport::new(&games));
+        }
+        3 => {
+            let day = args.day3.solution();
+            let schematic = day.parse(&input).map_err(|e| locate(e, path))?;
+            let report = day3::SchematicReport::new(&schematic, &day.rules)?;
+            match args.format {
+                ReportFormat::Table => print!("{report}"),
+                ReportFormat::Csv => print!("{}", report.csv()),
+                ReportFormat::Json => println!("{}", report.json()?),
+            }
+        }
+        day => bail!("Day {day} has no report."),
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Report> {
+    stable_eyre::install()?;
+
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Report(args) => report(args),
+    }
+}
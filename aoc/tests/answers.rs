@@ -0,0 +1,74 @@
+//! Runs every solver against the inputs listed in `answers.toml` and checks the answers.
+
+use std::path::{Path, PathBuf};
+
+use aoc_core::Part;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    answer: Vec<Entry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    day: u8,
+    input: PathBuf,
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+fn root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// Answers may be written either as integers or as strings in the manifest.
+fn expected(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[test]
+fn checked_in_inputs_give_expected_answers() {
+    let manifest = std::fs::read_to_string(root().join("answers.toml")).unwrap();
+    let manifest: Manifest = toml::from_str(&manifest).unwrap();
+
+    let mut failures = vec![];
+    for entry in &manifest.answer {
+        let input = std::fs::read_to_string(root().join(&entry.input))
+            .unwrap_or_else(|e| panic!("Failed to read {}: {e}", entry.input.display()));
+
+        let checks: Vec<(Part, String)> = [(Part::One, &entry.part1), (Part::Two, &entry.part2)]
+            .into_iter()
+            .filter_map(|(part, value)| value.as_ref().map(|v| (part, expected(v))))
+            .collect();
+        let parts: Vec<Part> = checks.iter().map(|(part, _)| *part).collect();
+
+        match aoc::solve(entry.day, &parts, &input) {
+            Ok(answers) => {
+                for ((part, expected), answer) in checks.iter().zip(answers) {
+                    if *expected != answer {
+                        failures.push(format!(
+                            "day {} {part} on {}: expected {expected}, got {answer}",
+                            entry.day,
+                            entry.input.display()
+                        ));
+                    }
+                }
+            }
+            Err(e) => failures.push(format!(
+                "day {} on {}: {e:#}",
+                entry.day,
+                entry.input.display()
+            )),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Mismatched answers:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,89 @@
+//! Checks that malformed inputs are reported at the line and column at fault.
+
+use aoc_core::{Diagnostic, Part};
+
+fn diagnostic(day: u8, input: &str) -> Diagnostic {
+    aoc::solve(day, &[Part::One], input)
+        .unwrap_err()
+        .downcast::<Diagnostic>()
+        .unwrap()
+}
+
+#[test]
+fn day2_points_at_missing_game_separator() {
+    let diagnostic = diagnostic(2, "Game 1: 3 blue, 4 red\nGame 2 3 blue\n").with_file("games.txt");
+
+    assert_eq!((diagnostic.line(), diagnostic.column()), (2, Some(8)));
+    assert_eq!(
+        diagnostic.to_string(),
+        "Expected ':' after Game declaration\n \
+         --> games.txt:2:8\n  \
+         |\n\
+         2 | Game 2 3 blue\n  \
+         |        ^ expected ':', found '3'"
+    );
+}
+
+#[test]
+fn day2_points_at_draws_missing_a_separator() {
+    let diagnostic = diagnostic(2, "Game 1: 3 blue 40 red\n");
+
+    assert_eq!((diagnostic.line(), diagnostic.column()), (1, Some(16)));
+    assert_eq!(
+        diagnostic.to_string(),
+        "Expected the game to end after its last draw\n \
+         --> 1:16\n  \
+         |\n\
+         1 | Game 1: 3 blue 40 red\n  \
+         |                ^ expected the end of the input, found '4'"
+    );
+}
+
+#[test]
+fn day4_points_at_unexpected_character() {
+    let diagnostic = diagnostic(4, "Card 1: 41 48 | 83 86\nCard 2: 1 2 x 3\n");
+
+    assert_eq!((diagnostic.line(), diagnostic.column()), (2, Some(13)));
+}
+
+#[test]
+fn keep_going_skips_malformed_lines() {
+    let input = "Card 1: 41 48 | 48 41\nCard 2: 1 2 x 3\nCard 3 1 | 1\nCard 4: 5 | 5\n";
+    let solved = aoc::solution(4)
+        .unwrap()
+        .solve(&[Part::One], input, aoc::ParseMode::KeepGoing)
+        .unwrap();
+
+    assert_eq!(solved.answers[0].as_ref().unwrap(), "3");
+    assert_eq!(
+        solved.skipped.iter().map(|d| d.line()).collect::<Vec<_>>(),
+        [2, 3]
+    );
+}
+
+#[test]
+fn columns_count_characters_rather_than_bytes() {
+    let diagnostic = diagnostic(2, "Game 1: 3 grün, x blue\n");
+
+    assert_eq!((diagnostic.line(), diagnostic.column()), (1, Some(17)));
+    assert_eq!(
+        diagnostic.to_string(),
+        "Expected a number, found 'x'.\n \
+         --> 1:17\n  \
+         |\n\
+         1 | Game 1: 3 grün, x blue\n  \
+         |                 ^ expected a number, found 'x'"
+    );
+}
+
+#[test]
+fn a_failing_part_leaves_the_other_answered() {
+    let day = day1::Day1::default().with_mode(day1::DecodeMode::PerPart);
+    let parts = [Part::One, Part::Two];
+    let solved =
+        aoc::DynSolution::solve(&day, &parts, "eightwothree\n", aoc::ParseMode::Strict).unwrap();
+
+    let error = solved.answers[0].as_ref().unwrap_err();
+    assert!(error.to_string().contains("Couldn't find a digit"));
+    assert_eq!(solved.answers[1].as_ref().unwrap(), "83");
+}
@@ -0,0 +1,50 @@
+//! Compares the automaton-based scanner against trying every word at every offset of a line.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day1::{DigitLexicon, DigitScanner};
+
+const INPUT: &str = include_str!("../input_part2_large.txt");
+
+fn starts_with_every_offset(lexicon: &DigitLexicon, input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let mut digits = line
+                .char_indices()
+                .filter_map(|(i, _)| lexicon.digit_at(&line[i..]));
+            let first = digits.next().unwrap();
+            let last = digits.next_back().unwrap_or(first);
+            first * 10 + last
+        })
+        .sum()
+}
+
+fn automaton(scanner: &DigitScanner, input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| scanner.first(line).unwrap().digit * 10 + scanner.last(line).unwrap().digit)
+        .sum()
+}
+
+fn bench_scanners(c: &mut Criterion) {
+    let lexicon = DigitLexicon::english();
+    let scanner = DigitScanner::new(&lexicon);
+    assert_eq!(
+        starts_with_every_offset(&lexicon, INPUT),
+        automaton(&scanner, INPUT)
+    );
+
+    let mut group = c.benchmark_group("input_part2_large");
+    group.bench_function("starts_with", |b| {
+        b.iter(|| starts_with_every_offset(&lexicon, black_box(INPUT)))
+    });
+    group.bench_function("automaton", |b| {
+        b.iter(|| automaton(&scanner, black_box(INPUT)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_scanners);
+criterion_main!(benches);
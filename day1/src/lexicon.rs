@@ -0,0 +1,147 @@
+use std::str::FromStr;
+
+use aoc_parse::{end, skip_whitespace, unsigned, Cursor, ParseError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LexiconError {
+    #[error("Expected an entry as <word>=<digit> on line {line}.")]
+    InvalidEntry { line: usize },
+    #[error("The digit on line {line} is not valid.")]
+    InvalidDigit {
+        line: usize,
+        #[source]
+        source: ParseError,
+    },
+    #[error("The word '{word}' on line {line} must spell a single digit, not {digit}.")]
+    NotADigit {
+        line: usize,
+        word: String,
+        digit: u32,
+    },
+    #[error("The lexicon does not spell out any digit.")]
+    Empty,
+}
+
+/// The words spelling out digits in a calibration document, e.g. "seven" for 7.
+///
+/// A digit may be spelled by several words. Plain ASCII digits are always recognised on top of the
+/// words of the lexicon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitLexicon {
+    words: Vec<(String, u32)>,
+}
+
+impl DigitLexicon {
+    /// The names of the built-in lexicons, as accepted by [`DigitLexicon::builtin`].
+    pub const BUILTIN: [&'static str; 4] = ["english", "french", "german", "spanish"];
+
+    /// Builds a lexicon where the word at index `i` spells the digit `i`.
+    fn numbered(words: [&str; 10]) -> Self {
+        DigitLexicon {
+            words: words
+                .iter()
+                .zip(0..)
+                .map(|(word, digit)| (word.to_string(), digit))
+                .collect(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Self::numbered([
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ])
+    }
+
+    pub fn french() -> Self {
+        Self::numbered([
+            "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ])
+    }
+
+    pub fn german() -> Self {
+        Self::numbered([
+            "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ])
+    }
+
+    pub fn spanish() -> Self {
+        Self::numbered([
+            "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ])
+    }
+
+    /// The built-in lexicon with the given name, if any.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Self::english()),
+            "french" => Some(Self::french()),
+            "german" => Some(Self::german()),
+            "spanish" => Some(Self::spanish()),
+            _ => None,
+        }
+    }
+
+    /// The words of the lexicon alongside the digit each of them spells.
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    /// The digit spelled at the very start of `text`, either as an ASCII digit or as a word.
+    pub fn digit_at(&self, text: &str) -> Option<u32> {
+        if let Some(digit) = text.chars().next().and_then(|c| c.to_digit(10)) {
+            return Some(digit);
+        }
+
+        self.words()
+            .find(|(word, _)| text.starts_with(word))
+            .map(|(_, digit)| digit)
+    }
+}
+
+impl Default for DigitLexicon {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl FromStr for DigitLexicon {
+    type Err = LexiconError;
+
+    /// Parses a lexicon written as one `<word>=<digit>` entry per line, e.g. `eins=1`. Blank lines
+    /// and lines starting with '#' are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = vec![];
+
+        for (index, text) in s.lines().enumerate() {
+            let text = text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            let line = index + 1;
+            let (word, digit) = match text.split_once('=') {
+                Some((word, digit)) if !word.trim().is_empty() => (word.trim().to_string(), digit),
+                _ => return Err(LexiconError::InvalidEntry { line }),
+            };
+
+            let invalid = |source| LexiconError::InvalidDigit { line, source };
+            let mut input = Cursor::new(digit);
+            skip_whitespace(&mut input);
+            let digit: u32 = unsigned(&mut input).map_err(invalid)?;
+            end(&mut input).map_err(invalid)?;
+
+            if digit > 9 {
+                return Err(LexiconError::NotADigit { line, word, digit });
+            }
+            words.push((word, digit));
+        }
+
+        if words.is_empty() {
+            return Err(LexiconError::Empty);
+        }
+
+        Ok(DigitLexicon { words })
+    }
+}
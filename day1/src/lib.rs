@@ -0,0 +1,132 @@
+use aoc_core::{Part, Solution};
+use stable_eyre::eyre::{bail, Report};
+
+mod lexicon;
+mod scanner;
+mod trace;
+
+pub use lexicon::{DigitLexicon, LexiconError};
+pub use scanner::{DigitMatch, DigitScanner, OverlapPolicy, TokenKind};
+pub use trace::{LineTrace, TracedToken};
+
+fn calibration_value(line: &str, scanner: &DigitScanner) -> Result<u32, Report> {
+    let (Some(first_digit), Some(last_digit)) = (scanner.first(line), scanner.last(line)) else {
+        bail!("Couldn't find a digit in line {}", line);
+    };
+
+    Ok(first_digit.digit * 10 + last_digit.digit)
+}
+
+/// Which digits count towards the calibration value of a line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Only ASCII digits, as in part 1 of the puzzle.
+    DigitsOnly,
+    /// ASCII digits and the words of the lexicon, as in part 2 of the puzzle.
+    #[default]
+    WithWords,
+    /// ASCII digits only in part 1 and the words of the lexicon as well in part 2, so both
+    /// answers of the puzzle come from one input.
+    PerPart,
+}
+
+/// Solves day 1, reading digits spelled out with the words of a [`DigitLexicon`].
+///
+/// Both parts read ASCII digits and words unless another [`DecodeMode`] is set. Overlapping words
+/// are both read unless another [`OverlapPolicy`] is set.
+#[derive(Debug, Clone)]
+pub struct Day1 {
+    lexicon: DigitLexicon,
+    mode: DecodeMode,
+    overlap: OverlapPolicy,
+    digits_only: DigitScanner,
+    with_words: DigitScanner,
+}
+
+impl Day1 {
+    pub fn new(lexicon: DigitLexicon) -> Self {
+        Day1 {
+            mode: DecodeMode::default(),
+            overlap: OverlapPolicy::default(),
+            digits_only: DigitScanner::digits_only(),
+            with_words: DigitScanner::new(&lexicon),
+            lexicon,
+        }
+    }
+
+    /// Decodes the parts in the given mode.
+    pub fn with_mode(mut self, mode: DecodeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Resolves words overlapping each other according to `policy`.
+    pub fn with_overlap(mut self, policy: OverlapPolicy) -> Self {
+        self.overlap = policy;
+        self.with_words = DigitScanner::with_policy(&self.lexicon, policy);
+        self
+    }
+
+    pub fn lexicon(&self) -> &DigitLexicon {
+        &self.lexicon
+    }
+
+    pub fn overlap(&self) -> OverlapPolicy {
+        self.overlap
+    }
+
+    pub fn mode(&self) -> DecodeMode {
+        self.mode
+    }
+
+    fn scanner(&self, part: Part) -> &DigitScanner {
+        match (self.mode, part) {
+            (DecodeMode::DigitsOnly, _) | (DecodeMode::PerPart, Part::One) => &self.digits_only,
+            (DecodeMode::WithWords, _) | (DecodeMode::PerPart, Part::Two) => &self.with_words,
+        }
+    }
+
+    /// Traces how the calibration value of every line is decoded when solving `part`.
+    pub fn trace(&self, input: &[String], part: Part) -> Vec<LineTrace> {
+        let scanner = self.scanner(part);
+
+        input
+            .iter()
+            .enumerate()
+            .map(|(index, line)| LineTrace::new(part, index, line, scanner))
+            .collect()
+    }
+
+    fn sum(&self, input: &[String], part: Part) -> Result<u32, Report> {
+        let scanner = self.scanner(part);
+        input
+            .iter()
+            .map(|line| calibration_value(line, scanner))
+            .sum()
+    }
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Day1::new(DigitLexicon::default())
+    }
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Report> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        self.sum(input, Part::One)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        self.sum(input, Part::Two)
+    }
+}
@@ -0,0 +1,165 @@
+use std::ops::Range;
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use serde::Serialize;
+
+use crate::DigitLexicon;
+
+/// How a digit was written in the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+/// A digit found in a line, either as an ASCII digit or as a word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitMatch {
+    pub digit: u32,
+    pub kind: TokenKind,
+    /// The byte range of the digit within the line.
+    pub span: Range<usize>,
+}
+
+/// How the digits of a line are picked when the words spelling them overlap, as in "eightwo".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Every offset of the line may start a digit, so "eightwo" holds both an eight and a two and
+    /// reads as 82. These are the semantics of the puzzle.
+    #[default]
+    Overlapping,
+    /// Digits are read left to right and none may start before the previous one ends, so
+    /// "eightwo" reads as 88, as a regex matching all the words would find. When several words
+    /// start at the same offset, the one listed first in the lexicon wins.
+    Greedy,
+    /// Like [`OverlapPolicy::Greedy`], but when several words start at the same offset the
+    /// longest one wins.
+    Longest,
+}
+
+/// Finds the first and last digits of a line, using automatons compiled once from a
+/// [`DigitLexicon`].
+///
+/// The first digit is the one starting leftmost. With [`OverlapPolicy::Overlapping`], the last
+/// digit is the one starting rightmost, found by running a second automaton over the reversed line,
+/// so both scans stop at the first offset holding a match. Other policies find the last digit by
+/// reading the whole line left to right.
+#[derive(Debug, Clone)]
+pub struct DigitScanner {
+    forward: AhoCorasick,
+    /// Only built for [`OverlapPolicy::Overlapping`].
+    backward: Option<AhoCorasick>,
+    /// The digit spelled by each pattern of the automatons.
+    digits: Vec<u32>,
+}
+
+impl DigitScanner {
+    /// A scanner recognising ASCII digits as well as the words of `lexicon`, allowing them to
+    /// overlap.
+    pub fn new(lexicon: &DigitLexicon) -> Self {
+        Self::with_policy(lexicon, OverlapPolicy::default())
+    }
+
+    /// A scanner recognising ASCII digits as well as the words of `lexicon`, resolving overlapping
+    /// words according to `policy`.
+    pub fn with_policy(lexicon: &DigitLexicon, policy: OverlapPolicy) -> Self {
+        Self::with_words(lexicon.words(), policy)
+    }
+
+    /// A scanner recognising ASCII digits only.
+    pub fn digits_only() -> Self {
+        Self::with_words(std::iter::empty(), OverlapPolicy::default())
+    }
+
+    fn with_words<'a>(words: impl Iterator<Item = (&'a str, u32)>, policy: OverlapPolicy) -> Self {
+        let ascii = ('0'..='9').map(|c| c.to_string()).zip(0..);
+        let words = words.map(|(word, digit)| (word.to_string(), digit));
+        let (patterns, digits): (Vec<String>, Vec<u32>) = ascii.chain(words).unzip();
+
+        let (forward, backward) = match policy {
+            OverlapPolicy::Overlapping => {
+                // The automatons work on bytes, so reversing the bytes of both the patterns and the
+                // haystack finds the same matches as reading the text backwards.
+                let reversed: Vec<Vec<u8>> = patterns
+                    .iter()
+                    .map(|pattern| pattern.bytes().rev().collect())
+                    .collect();
+                // The backward automaton reports every match so that a word ending a longer one, as
+                // "even" ends "seven", is not hidden by it.
+                (
+                    build(&patterns, MatchKind::LeftmostFirst),
+                    Some(build(&reversed, MatchKind::Standard)),
+                )
+            }
+            OverlapPolicy::Greedy => (build(&patterns, MatchKind::LeftmostFirst), None),
+            OverlapPolicy::Longest => (build(&patterns, MatchKind::LeftmostLongest), None),
+        };
+
+        DigitScanner {
+            forward,
+            backward,
+            digits,
+        }
+    }
+
+    fn digit_match(&self, pattern: usize, span: Range<usize>) -> DigitMatch {
+        DigitMatch {
+            digit: self.digits[pattern],
+            // The ASCII digits are the first ten patterns.
+            kind: if pattern < 10 {
+                TokenKind::Digit
+            } else {
+                TokenKind::Word
+            },
+            span,
+        }
+    }
+
+    /// The leftmost digit of `line`.
+    pub fn first(&self, line: &str) -> Option<DigitMatch> {
+        self.forward
+            .find(line)
+            .map(|found| self.digit_match(found.pattern().as_usize(), found.range()))
+    }
+
+    /// The last digit of `line`: the one starting rightmost when digits may overlap, or the last
+    /// one read left to right otherwise. Of several words starting at the same offset, the one
+    /// listed first in the lexicon wins, as for [`DigitScanner::first`].
+    pub fn last(&self, line: &str) -> Option<DigitMatch> {
+        let Some(backward) = &self.backward else {
+            return self
+                .forward
+                .find_iter(line)
+                .last()
+                .map(|found| self.digit_match(found.pattern().as_usize(), found.range()));
+        };
+
+        let reversed: Vec<u8> = line.bytes().rev().collect();
+
+        // Overlapping matches come by increasing end within the reversed line, that is by
+        // decreasing start within the line, so the first ones start rightmost.
+        let mut matches = backward.find_overlapping_iter(&reversed);
+        let first = matches.next()?;
+        let found =
+            matches
+                .take_while(|found| found.end() == first.end())
+                .fold(first, |best, found| {
+                    if found.pattern() < best.pattern() {
+                        found
+                    } else {
+                        best
+                    }
+                });
+
+        let span = line.len() - found.end()..line.len() - found.start();
+        Some(self.digit_match(found.pattern().as_usize(), span))
+    }
+}
+
+fn build<P: AsRef<[u8]>>(patterns: &[P], kind: MatchKind) -> AhoCorasick {
+    AhoCorasick::builder()
+        .match_kind(kind)
+        .build(patterns)
+        .expect("the digit patterns are few and short enough to always build")
+}
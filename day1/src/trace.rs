@@ -0,0 +1,130 @@
+use aoc_core::{table, Part};
+use serde::Serialize;
+
+use crate::{DigitMatch, DigitScanner, TokenKind};
+
+/// A digit matched in a line, as shown in a trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TracedToken {
+    /// The text of the line the digit was read from.
+    pub text: String,
+    pub digit: u32,
+    pub kind: TokenKind,
+    /// The byte offset of the first byte of the digit.
+    pub start: usize,
+    /// The byte offset right after the last byte of the digit.
+    pub end: usize,
+}
+
+impl TracedToken {
+    fn new(line: &str, found: DigitMatch) -> Self {
+        TracedToken {
+            text: line[found.span.clone()].to_string(),
+            digit: found.digit,
+            kind: found.kind,
+            start: found.span.start,
+            end: found.span.end,
+        }
+    }
+}
+
+/// How the calibration value of a single line was decoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LineTrace {
+    #[serde(serialize_with = "serialize_part")]
+    pub part: Part,
+    /// The line number, counting from 1.
+    pub line: usize,
+    pub first: Option<TracedToken>,
+    pub last: Option<TracedToken>,
+    /// The calibration value, missing if the line holds no digit.
+    pub value: Option<u32>,
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+fn serialize_part<S: serde::Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part_number(*part))
+}
+
+impl LineTrace {
+    pub(crate) fn new(part: Part, index: usize, line: &str, scanner: &DigitScanner) -> Self {
+        let first = scanner
+            .first(line)
+            .map(|found| TracedToken::new(line, found));
+        let last = scanner
+            .last(line)
+            .map(|found| TracedToken::new(line, found));
+        let value = first
+            .as_ref()
+            .zip(last.as_ref())
+            .map(|(first, last)| first.digit * 10 + last.digit);
+
+        LineTrace {
+            part,
+            line: index + 1,
+            first,
+            last,
+            value,
+        }
+    }
+
+    /// Renders traces as a table with aligned columns.
+    pub fn table(traces: &[LineTrace]) -> String {
+        let header = [
+            "Part", "Line", "First", "At", "Kind", "Last", "At", "Kind", "Value",
+        ];
+        let rows: Vec<[String; 9]> = traces
+            .iter()
+            .map(|trace| {
+                let [first, first_at, first_kind] = token_cells(&trace.first);
+                let [last, last_at, last_kind] = token_cells(&trace.last);
+                [
+                    part_number(trace.part).to_string(),
+                    trace.line.to_string(),
+                    first,
+                    first_at,
+                    first_kind,
+                    last,
+                    last_at,
+                    last_kind,
+                    trace
+                        .value
+                        .map_or("-".to_string(), |value| value.to_string()),
+                ]
+            })
+            .collect();
+
+        table(header, rows)
+    }
+
+    /// Renders traces as JSON Lines, one object per line of the input.
+    pub fn json_lines(traces: &[LineTrace]) -> serde_json::Result<String> {
+        let mut lines = String::new();
+        for trace in traces {
+            lines.push_str(&serde_json::to_string(trace)?);
+            lines.push('\n');
+        }
+
+        Ok(lines)
+    }
+}
+
+fn token_cells(token: &Option<TracedToken>) -> [String; 3] {
+    match token {
+        Some(token) => [
+            token.text.clone(),
+            format!("{}..{}", token.start, token.end),
+            match token.kind {
+                TokenKind::Digit => "digit".to_string(),
+                TokenKind::Word => "word".to_string(),
+            },
+        ],
+        None => ["-".to_string(), "-".to_string(), "-".to_string()],
+    }
+}
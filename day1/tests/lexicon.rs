@@ -0,0 +1,38 @@
+use aoc_core::Solution;
+use day1::{Day1, DigitLexicon};
+
+fn part2(lexicon: DigitLexicon, input: &str) -> u32 {
+    let day = Day1::new(lexicon);
+    day.part2(&day.parse(input).unwrap()).unwrap()
+}
+
+#[test]
+fn builtin_lexicons_decode_their_words() {
+    assert_eq!(part2(DigitLexicon::english(), "xtwone3four\n"), 24);
+    assert_eq!(part2(DigitLexicon::french(), "deuxabc7huit\n"), 28);
+    assert_eq!(
+        part2(DigitLexicon::german(), "drei7zwölf\nfünfxyz\n"),
+        37 + 55
+    );
+    assert_eq!(part2(DigitLexicon::spanish(), "nueve1cero\n"), 90);
+}
+
+#[test]
+fn lexicon_files_list_one_word_per_line() {
+    let lexicon: DigitLexicon = "# Spanish, partially\nuno=1\n\ndos = 2\n".parse().unwrap();
+
+    assert_eq!(
+        lexicon.words().collect::<Vec<_>>(),
+        [("uno", 1), ("dos", 2)]
+    );
+    assert_eq!(part2(lexicon, "xunox4dosy\n"), 12);
+}
+
+#[test]
+fn malformed_lexicon_files_are_rejected() {
+    assert!("".parse::<DigitLexicon>().is_err());
+    assert!("uno".parse::<DigitLexicon>().is_err());
+    assert!("=1".parse::<DigitLexicon>().is_err());
+    assert!("uno=12".parse::<DigitLexicon>().is_err());
+    assert!("uno=one".parse::<DigitLexicon>().is_err());
+}
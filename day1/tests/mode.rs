@@ -0,0 +1,42 @@
+use aoc_core::Solution;
+use day1::{Day1, DecodeMode};
+
+const SAMPLE: &str = "two1nine\n7pqrstsixteen\nxtwone3four\n";
+
+#[test]
+fn both_parts_read_words_by_default() {
+    let day = Day1::default();
+    let input = day.parse(SAMPLE).unwrap();
+
+    assert_eq!(day.mode(), DecodeMode::WithWords);
+    assert_eq!(day.part1(&input).unwrap(), 29 + 76 + 24);
+    assert_eq!(day.part2(&input).unwrap(), 29 + 76 + 24);
+}
+
+#[test]
+fn per_part_mode_reads_words_in_part_2_only() {
+    let day = Day1::default().with_mode(DecodeMode::PerPart);
+    let input = day.parse(SAMPLE).unwrap();
+
+    assert_eq!(day.part1(&input).unwrap(), 11 + 77 + 33);
+    assert_eq!(day.part2(&input).unwrap(), 29 + 76 + 24);
+}
+
+#[test]
+fn mode_applies_to_both_parts() {
+    let day = Day1::default().with_mode(DecodeMode::WithWords);
+    let input = day.parse(SAMPLE).unwrap();
+    assert_eq!(day.part1(&input).unwrap(), 29 + 76 + 24);
+
+    let day = Day1::default().with_mode(DecodeMode::DigitsOnly);
+    assert_eq!(day.part2(&input).unwrap(), 11 + 77 + 33);
+}
+
+#[test]
+fn digits_only_ignores_words() {
+    let day = Day1::default().with_mode(DecodeMode::PerPart);
+    let input = day.parse("eightwothree\n").unwrap();
+
+    assert!(day.part1(&input).is_err());
+    assert_eq!(day.part2(&input).unwrap(), 83);
+}
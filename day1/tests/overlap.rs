@@ -0,0 +1,61 @@
+use aoc_core::Solution;
+use day1::{Day1, DigitLexicon, DigitScanner, OverlapPolicy};
+
+#[test]
+fn overlapping_words_are_both_read_by_default() {
+    let day = Day1::default();
+    let input = day.parse("eightwo\nxoneight3sevenine\n").unwrap();
+
+    assert_eq!(day.overlap(), OverlapPolicy::Overlapping);
+    assert_eq!(day.part2(&input).unwrap(), 82 + 19);
+}
+
+#[test]
+fn greedy_skips_words_overlapping_the_previous_one() {
+    let day = Day1::default().with_overlap(OverlapPolicy::Greedy);
+    let input = day.parse("eightwo\nxoneight3sevenine\n").unwrap();
+
+    assert_eq!(day.part2(&input).unwrap(), 88 + 17);
+}
+
+#[test]
+fn greedy_and_longest_differ_on_words_sharing_a_prefix() {
+    let lexicon: DigitLexicon = "two=2\none=1\ntwone=9\n".parse().unwrap();
+    let line = "twone";
+
+    let overlapping = DigitScanner::with_policy(&lexicon, OverlapPolicy::Overlapping);
+    assert_eq!(overlapping.first(line).unwrap().digit, 2);
+    assert_eq!(overlapping.last(line).unwrap().digit, 1);
+
+    let greedy = DigitScanner::with_policy(&lexicon, OverlapPolicy::Greedy);
+    assert_eq!(greedy.first(line).unwrap().digit, 2);
+    assert_eq!(greedy.last(line).unwrap().digit, 2);
+
+    let longest = DigitScanner::with_policy(&lexicon, OverlapPolicy::Longest);
+    let found = longest.last(line).unwrap();
+    assert_eq!((found.digit, found.span), (9, 0..5));
+}
+
+#[test]
+fn last_digit_starts_rightmost_when_words_nest() {
+    let lexicon: DigitLexicon = "seven=7\neven=2\n".parse().unwrap();
+    let scanner = DigitScanner::new(&lexicon);
+
+    let found = scanner.first("xseven").unwrap();
+    assert_eq!((found.digit, found.span), (7, 1..6));
+    let found = scanner.last("xseven").unwrap();
+    assert_eq!((found.digit, found.span), (2, 2..6));
+
+    let day = Day1::new(lexicon);
+    let input = day.parse("xseven\neven7\n").unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 72 + 27);
+}
+
+#[test]
+fn last_digit_prefers_the_lexicon_order_at_the_same_offset() {
+    let lexicon: DigitLexicon = "on=4\none=1\n".parse().unwrap();
+    let scanner = DigitScanner::new(&lexicon);
+
+    assert_eq!(scanner.first("xone").unwrap().digit, 4);
+    assert_eq!(scanner.last("xone").unwrap().digit, 4);
+}
@@ -0,0 +1,38 @@
+use aoc_core::{Part, Solution};
+use day1::{Day1, LineTrace, TokenKind};
+
+#[test]
+fn trace_records_matched_tokens() {
+    let day = Day1::default();
+    let input = day.parse("xtwone3four\nnothing\n").unwrap();
+    let traces = day.trace(&input, Part::Two);
+
+    let first = traces[0].first.as_ref().unwrap();
+    let last = traces[0].last.as_ref().unwrap();
+    assert_eq!(
+        (first.text.as_str(), first.kind, first.start, first.end),
+        ("two", TokenKind::Word, 1, 4)
+    );
+    assert_eq!(
+        (last.text.as_str(), last.kind, last.start, last.end),
+        ("four", TokenKind::Word, 7, 11)
+    );
+    assert_eq!(traces[0].value, Some(24));
+
+    assert_eq!((traces[1].line, traces[1].value), (2, None));
+}
+
+#[test]
+fn trace_renders_as_json_lines() {
+    let day = Day1::default();
+    let input = day.parse("a1b\n").unwrap();
+    let traces = day.trace(&input, Part::One);
+
+    assert_eq!(
+        LineTrace::json_lines(&traces).unwrap(),
+        "{\"part\":1,\"line\":1,\
+         \"first\":{\"text\":\"1\",\"digit\":1,\"kind\":\"digit\",\"start\":1,\"end\":2},\
+         \"last\":{\"text\":\"1\",\"digit\":1,\"kind\":\"digit\",\"start\":1,\"end\":2},\
+         \"value\":11}\n"
+    );
+}
@@ -0,0 +1,145 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_parse::{end, keyword, unsigned, word, Cursor, ParseError};
+
+use crate::{Cube, CubeColor};
+
+#[derive(Debug, thiserror::Error)]
+pub enum BagError {
+    #[error("Expected the contents of the bag as <color>=<amount>, found '{0}'.")]
+    InvalidEntry(String, #[source] ParseError),
+    #[error("The bag lists {0} more than once.")]
+    DuplicateColor(CubeColor),
+}
+
+/// The cubes the elf may have put in the bag. Colours the bag does not list are not in it at all.
+#[derive(Debug, Clone)]
+pub struct Bag {
+    /// The amount of cubes of each colour, in the order the colours were added.
+    cubes: Vec<(CubeColor, u32)>,
+}
+
+impl Bag {
+    /// A bag without any cubes.
+    pub fn empty() -> Self {
+        Bag { cubes: vec![] }
+    }
+
+    /// Puts `amount` cubes of the given colour in the bag, replacing any cubes of that colour.
+    pub fn with(mut self, color: &str, amount: u32) -> Self {
+        match self.cubes.iter_mut().find(|(c, _)| c.as_str() == color) {
+            Some((_, current)) => *current = amount,
+            None => self.cubes.push((CubeColor::new(color), amount)),
+        }
+        self
+    }
+
+    /// How many cubes of the given colour are in the bag.
+    pub fn amount(&self, color: &str) -> u32 {
+        self.cubes
+            .iter()
+            .find(|(c, _)| c.as_str() == color)
+            .map_or(0, |(_, amount)| *amount)
+    }
+
+    /// Whether the bag lists the given colour, even if it holds no cubes of it.
+    pub fn lists(&self, color: &CubeColor) -> bool {
+        self.cubes.iter().any(|(c, _)| c == color)
+    }
+
+    /// The smallest bag holding the cubes of both bags.
+    pub fn covering(mut self, other: &Bag) -> Self {
+        for (color, amount) in other.cubes() {
+            if amount > self.amount(color.as_str()) || !self.lists(color) {
+                self = self.with(color.as_str(), amount);
+            }
+        }
+        self
+    }
+
+    /// The product of the amounts of cubes of every colour in the bag, or `None` if it does not
+    /// fit in 64 bits.
+    pub fn power(&self) -> Option<u64> {
+        self.cubes().try_fold(1u64, |power, (_, amount)| {
+            power.checked_mul(u64::from(amount))
+        })
+    }
+
+    /// The colours in the bag alongside the amount of cubes of each.
+    pub fn cubes(&self) -> impl Iterator<Item = (&CubeColor, u32)> {
+        self.cubes.iter().map(|(color, amount)| (color, *amount))
+    }
+
+    /// Whether the elf could have drawn `cube` out of this bag.
+    pub(crate) fn holds(&self, cube: &Cube) -> bool {
+        cube.amount <= self.amount(cube.color.as_str())
+    }
+}
+
+impl PartialEq for Bag {
+    /// Bags are equal when they list the same colours with the same amounts, in any order.
+    fn eq(&self, other: &Self) -> bool {
+        self.cubes.len() == other.cubes.len()
+            && self
+                .cubes()
+                .all(|(color, amount)| other.lists(color) && other.amount(color.as_str()) == amount)
+    }
+}
+
+impl Eq for Bag {}
+
+impl Default for Bag {
+    /// The bag from the puzzle: 12 red cubes, 13 green cubes, and 14 blue cubes.
+    fn default() -> Self {
+        Bag::empty()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+}
+
+fn parse_entry(entry: &str) -> Result<(CubeColor, u32), ParseError> {
+    let mut input = Cursor::new(entry);
+
+    let color = CubeColor::new(word(&mut input)?);
+    keyword(&mut input, "=")?;
+    let amount = unsigned(&mut input)?;
+    end(&mut input)?;
+
+    Ok((color, amount))
+}
+
+impl FromStr for Bag {
+    type Err = BagError;
+
+    /// Parses the contents of a bag as `<color>=<amount>` entries separated by commas or
+    /// whitespace, e.g. `red=20,green=5,blue=9`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bag = Bag::empty();
+
+        for entry in s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|entry| !entry.is_empty())
+        {
+            let (color, amount) =
+                parse_entry(entry).map_err(|e| BagError::InvalidEntry(entry.to_string(), e))?;
+            if bag.lists(&color) {
+                return Err(BagError::DuplicateColor(color));
+            }
+            bag.cubes.push((color, amount));
+        }
+
+        Ok(bag)
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self
+            .cubes()
+            .map(|(color, amount)| format!("{color}={amount}"))
+            .collect();
+
+        write!(f, "{}", entries.join(","))
+    }
+}
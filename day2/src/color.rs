@@ -0,0 +1,48 @@
+use std::{borrow::Borrow, collections::HashSet, fmt::Display, sync::Arc};
+
+/// The colour of a cube, e.g. "red" or "purple".
+///
+/// Any colour may appear in the input. Colours are cheap to clone, and those parsed through the
+/// same [`Palette`] share their name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CubeColor(Arc<str>);
+
+impl CubeColor {
+    pub fn new(name: &str) -> Self {
+        CubeColor(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for CubeColor {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CubeColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+/// The set of colours seen so far, interning each colour the first time it is seen.
+#[derive(Debug, Default)]
+pub struct Palette {
+    colors: HashSet<CubeColor>,
+}
+
+impl Palette {
+    pub fn intern(&mut self, name: &str) -> CubeColor {
+        if let Some(color) = self.colors.get(name) {
+            return color.clone();
+        }
+
+        let color = CubeColor::new(name);
+        self.colors.insert(color.clone());
+        color
+    }
+}
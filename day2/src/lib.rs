@@ -0,0 +1,257 @@
+use aoc_core::{parse_lines, parse_lines_recovering, Diagnostic, Solution};
+use aoc_parse::{end, keyword, separator, skip_whitespace, unsigned, word, Cursor};
+use stable_eyre::eyre::{bail, Context, Report};
+
+mod bag;
+mod color;
+mod report;
+
+pub use bag::{Bag, BagError};
+pub use color::{CubeColor, Palette};
+pub use report::{BagReport, ColorStats, LimitThreshold};
+
+/// A power too large for the answer of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OverflowError {
+    #[error("The power of game {0} does not fit in 64 bits.")]
+    Power(u32),
+    #[error("The sum of the powers does not fit in 64 bits once game {0} is added.")]
+    PowerSum(u32),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Separator {
+    Showings,
+    Cubes,
+}
+
+impl Separator {
+    fn as_char(&self) -> char {
+        match self {
+            Separator::Showings => ';',
+            Separator::Cubes => ',',
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Token {
+    Game(u32),
+    Number(u32),
+    Color(CubeColor),
+    Separator(Separator),
+}
+
+fn next_number(input: &mut Cursor<'_>) -> Result<Token, Report> {
+    Ok(Token::Number(unsigned(input)?))
+}
+
+fn next_color(input: &mut Cursor<'_>, palette: &mut Palette) -> Result<Token, Report> {
+    let name = word(input).wrap_err("Did not get a valid color")?;
+
+    Ok(Token::Color(palette.intern(name)))
+}
+
+fn next_separator(input: &mut Cursor<'_>) -> Result<Token, Report> {
+    let separators = [Separator::Showings, Separator::Cubes];
+    let found = separator(input, &separators.map(|sep| sep.as_char()))
+        .wrap_err("Expected a separator - ',' or ';'")?;
+
+    Ok(Token::Separator(
+        separators
+            .into_iter()
+            .find(|sep| sep.as_char() == found)
+            .unwrap(),
+    ))
+}
+
+fn next_game(input: &mut Cursor<'_>) -> Result<Token, Report> {
+    keyword(input, "Game").wrap_err("Expected 'Game' string")?;
+    skip_whitespace(input);
+    let num = unsigned(input).wrap_err("Expected a number after 'Game'")?;
+    skip_whitespace(input);
+    keyword(input, ":").wrap_err("Expected ':' after Game declaration")?;
+
+    Ok(Token::Game(num))
+}
+
+fn tokenize_line(line: &str, palette: &mut Palette) -> Result<Vec<Token>, Report> {
+    let mut result = vec![];
+    let mut stream = Cursor::new(line);
+
+    result.push(next_game(&mut stream)?);
+    loop {
+        skip_whitespace(&mut stream);
+        result.push(next_number(&mut stream)?);
+        skip_whitespace(&mut stream);
+        result.push(next_color(&mut stream, palette)?);
+        skip_whitespace(&mut stream);
+
+        match next_separator(&mut stream) {
+            Ok(token) => result.push(token),
+            Err(_) => break,
+        }
+    }
+    end(&mut stream).wrap_err("Expected the game to end after its last draw")?;
+
+    Ok(result)
+}
+
+#[derive(Debug)]
+struct Cube {
+    amount: u32,
+    color: CubeColor,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    game_number: u32,
+    showings: Vec<Vec<Cube>>,
+}
+
+impl Game {
+    pub fn number(&self) -> u32 {
+        self.game_number
+    }
+
+    /// Whether every showing of the game could have been drawn out of `bag`.
+    pub fn valid(&self, bag: &Bag) -> bool {
+        for showing in &self.showings {
+            for cube in showing {
+                if !bag.holds(cube) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// The fewest cubes of each colour the bag must have held for the game to be possible. Only
+    /// the colours shown during the game are listed.
+    pub fn minimal_bag(&self) -> Bag {
+        let mut bag = Bag::empty();
+
+        for cube in self.showings.iter().flatten() {
+            if cube.amount > bag.amount(cube.color.as_str()) || !bag.lists(&cube.color) {
+                bag = bag.with(cube.color.as_str(), cube.amount);
+            }
+        }
+
+        bag
+    }
+
+    /// The power of the minimal bag of the game.
+    pub fn power(&self) -> Result<u64, OverflowError> {
+        self.minimal_bag()
+            .power()
+            .ok_or(OverflowError::Power(self.game_number))
+    }
+
+    /// Every cube drawn during the game, across all showings.
+    pub fn draws(&self) -> impl Iterator<Item = (&CubeColor, u32)> {
+        self.showings
+            .iter()
+            .flatten()
+            .map(|cube| (&cube.color, cube.amount))
+    }
+}
+
+fn parse_game(mut input: impl Iterator<Item = Token>) -> Result<Game, Report> {
+    let game_number = if let Some(Token::Game(num)) = input.next() {
+        num
+    } else {
+        bail!("Expected line to start with a game declaration.");
+    };
+
+    let mut showings = vec![];
+    while let Some(showing) = parse_showing(&mut input)? {
+        showings.push(showing);
+    }
+
+    Ok(Game {
+        game_number,
+        showings,
+    })
+}
+
+fn parse_showing(input: &mut impl Iterator<Item = Token>) -> Result<Option<Vec<Cube>>, Report> {
+    let mut cube_vec = vec![];
+    loop {
+        let amount = match input.next() {
+            Some(Token::Number(num)) => num,
+            Some(token) => bail!("Expected number token, found: {token:?}"),
+            None => break Ok(cube_vec),
+        };
+
+        let color = if let Some(Token::Color(col)) = input.next() {
+            col
+        } else {
+            bail!("Expected to find a color of a cube");
+        };
+
+        cube_vec.push(Cube { amount, color });
+
+        match input.next() {
+            Some(Token::Separator(Separator::Cubes)) => continue,
+            Some(Token::Separator(Separator::Showings)) | None => break Ok(cube_vec),
+            Some(t) => bail!("Expected separator or end of input, got token {:?}", t),
+        }
+    }
+    .map(|res| if !res.is_empty() { Some(res) } else { None })
+}
+
+fn parse_line(line: &str, palette: &mut Palette) -> Result<Game, Report> {
+    let tokens = tokenize_line(line, palette)?;
+    parse_game(tokens.into_iter())
+}
+
+/// Solves day 2, checking which games are possible against the contents of `bag`.
+#[derive(Debug, Default)]
+pub struct Day2 {
+    pub bag: Bag,
+}
+
+impl Day2 {
+    /// The games which could have been played with the cubes in the bag.
+    pub fn possible_games<'a>(&'a self, games: &'a [Game]) -> impl Iterator<Item = &'a Game> {
+        games.iter().filter(|game| game.valid(&self.bag))
+    }
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Report> {
+        let mut palette = Palette::default();
+        parse_lines(input, |line| parse_line(line, &mut palette))
+    }
+
+    fn parse_recovering(&self, input: &str) -> Result<(Self::Input, Vec<Diagnostic>), Report> {
+        let mut palette = Palette::default();
+        Ok(parse_lines_recovering(input, |line| {
+            parse_line(line, &mut palette)
+        }))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        Ok(self
+            .possible_games(input)
+            .map(|game| u64::from(game.number()))
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        let mut sum: u64 = 0;
+        for game in input {
+            sum = sum
+                .checked_add(game.power()?)
+                .ok_or(OverflowError::PowerSum(game.number()))?;
+        }
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use aoc_core::table;
+
+use crate::{Bag, CubeColor, Game};
+
+/// How many cubes of a colour were drawn at once, across every showing of every game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStats {
+    pub color: CubeColor,
+    /// How many times cubes of this colour were drawn.
+    pub draws: usize,
+    pub min: u32,
+    pub mean: f64,
+    pub max: u32,
+}
+
+/// The games which become impossible once the limit of a colour drops below `limit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitThreshold {
+    pub color: CubeColor,
+    pub limit: u32,
+    pub games: Vec<u32>,
+}
+
+/// Statistics about the cubes drawn across all the games of an input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BagReport {
+    /// The smallest bag with which every game is possible.
+    pub minimal_bag: Bag,
+    /// The statistics of every colour, in the order of the minimal bag.
+    pub colors: Vec<ColorStats>,
+    /// Starting from the minimal bag, the games made impossible by lowering the limit of each
+    /// colour, from the highest limit to the lowest.
+    pub thresholds: Vec<LimitThreshold>,
+}
+
+impl BagReport {
+    pub fn new(games: &[Game]) -> Self {
+        let minimal_bag = games
+            .iter()
+            .fold(Bag::empty(), |bag, game| bag.covering(&game.minimal_bag()));
+
+        let colors = minimal_bag
+            .cubes()
+            .map(|(color, _)| {
+                let amounts: Vec<u32> = games
+                    .iter()
+                    .flat_map(|game| game.draws())
+                    .filter(|(c, _)| *c == color)
+                    .map(|(_, amount)| amount)
+                    .collect();
+
+                ColorStats {
+                    color: color.clone(),
+                    draws: amounts.len(),
+                    min: amounts.iter().copied().min().unwrap_or(0),
+                    mean: amounts.iter().map(|&amount| u64::from(amount)).sum::<u64>() as f64
+                        / amounts.len() as f64,
+                    max: amounts.iter().copied().max().unwrap_or(0),
+                }
+            })
+            .collect();
+
+        let thresholds = minimal_bag
+            .cubes()
+            .flat_map(|(color, _)| {
+                let mut by_limit: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+                for game in games {
+                    let needed = game.minimal_bag().amount(color.as_str());
+                    if needed > 0 {
+                        by_limit.entry(needed).or_default().push(game.number());
+                    }
+                }
+
+                by_limit
+                    .into_iter()
+                    .rev()
+                    .map(|(limit, games)| LimitThreshold {
+                        color: color.clone(),
+                        limit,
+                        games,
+                    })
+            })
+            .collect();
+
+        BagReport {
+            minimal_bag,
+            colors,
+            thresholds,
+        }
+    }
+}
+
+impl Display for BagReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Minimal bag for all games: {}", self.minimal_bag)?;
+        writeln!(f)?;
+
+        let rows = self
+            .colors
+            .iter()
+            .map(|stats| {
+                [
+                    stats.color.to_string(),
+                    stats.draws.to_string(),
+                    stats.min.to_string(),
+                    format!("{:.2}", stats.mean),
+                    stats.max.to_string(),
+                ]
+            })
+            .collect();
+        write!(
+            f,
+            "{}",
+            table(["Color", "Draws", "Min", "Mean", "Max"], rows)
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "Games made impossible by lowering a limit:")?;
+        for threshold in &self.thresholds {
+            let games: Vec<String> = threshold.games.iter().map(u32::to_string).collect();
+            writeln!(
+                f,
+                "{} < {}: {}",
+                threshold.color,
+                threshold.limit,
+                games.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_core::Solution;
+use day2::{Bag, Day2};
+
+const SAMPLE: &str = include_str!("../1_sample.txt");
+
+fn possible_games(bag: &str) -> Vec<u32> {
+    let day = Day2 {
+        bag: bag.parse().unwrap(),
+    };
+    let games = day.parse(SAMPLE).unwrap();
+
+    day.possible_games(&games)
+        .map(|game| game.number())
+        .collect()
+}
+
+#[test]
+fn default_bag_is_the_puzzle_bag() {
+    assert_eq!(
+        "red=12,green=13,blue=14".parse::<Bag>().unwrap(),
+        Bag::default()
+    );
+    assert_eq!(possible_games("red=12,green=13,blue=14"), [0, 1, 2, 5]);
+}
+
+#[test]
+fn possible_games_follow_the_bag() {
+    assert_eq!(
+        possible_games("red=20,green=13,blue=15"),
+        [0, 1, 2, 3, 4, 5]
+    );
+    assert_eq!(possible_games("red=5 green=3\nblue=6"), [0, 1, 2]);
+}
+
+#[test]
+fn colors_missing_from_the_bag_are_empty() {
+    let bag: Bag = "red=20,blue=20".parse().unwrap();
+
+    assert_eq!(bag.amount("green"), 0);
+    assert_eq!(possible_games("red=20,blue=20"), [0]);
+}
+
+#[test]
+fn malformed_bags_are_rejected() {
+    assert!("red=5,red=3".parse::<Bag>().is_err());
+    assert!("red:5".parse::<Bag>().is_err());
+    assert!("red=".parse::<Bag>().is_err());
+}
+
+#[test]
+fn any_color_can_be_drawn() {
+    let input = "Game 1: 3 purple, 2 red; 1 purple\nGame 2: 4 teal\n";
+    let default = Day2::default();
+    let games = default.parse(input).unwrap();
+
+    assert_eq!(default.part1(&games).unwrap(), 0);
+    assert_eq!(default.part2(&games).unwrap(), 3 * 2 + 4);
+
+    let day = Day2 {
+        bag: "purple=3,red=2".parse().unwrap(),
+    };
+    assert_eq!(day.part1(&games).unwrap(), 1);
+}
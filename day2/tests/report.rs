@@ -0,0 +1,103 @@
+use aoc_core::Solution;
+use day2::{Bag, BagReport, Day2, OverflowError};
+
+const SAMPLE: &str = include_str!("../1_sample.txt");
+
+#[test]
+fn minimal_bag_of_a_game() {
+    let games = Day2::default().parse(SAMPLE).unwrap();
+    let game = games.iter().find(|game| game.number() == 3).unwrap();
+
+    assert_eq!(
+        game.minimal_bag(),
+        "red=20,green=13,blue=6".parse::<Bag>().unwrap()
+    );
+    assert_eq!(game.power(), Ok(20 * 13 * 6));
+}
+
+#[test]
+fn powers_of_many_colours_widen_to_64_bits() {
+    let day = Day2::default();
+    let games = day
+        .parse("Game 1: 20 a, 20 b, 20 c, 20 d, 20 e, 20 f, 20 g, 20 h\n")
+        .unwrap();
+
+    assert_eq!(games[0].power(), Ok(20u64.pow(8)));
+    assert_eq!(day.part2(&games).unwrap(), 20u64.pow(8));
+}
+
+#[test]
+fn overflowing_powers_name_their_game() {
+    let colours: Vec<String> = ('a'..='p').map(|colour| format!("20 {colour}")).collect();
+    let games = Day2::default()
+        .parse(&format!("Game 1: {}\n", colours.join(", ")))
+        .unwrap();
+    assert_eq!(games[0].power(), Err(OverflowError::Power(1)));
+
+    let day = Day2::default();
+    let games = day
+        .parse("Game 1: 4294967295 a, 4294967295 b\nGame 2: 4294967295 a, 4294967295 b\n")
+        .unwrap();
+    let error = day.part2(&games).unwrap_err();
+    assert_eq!(error.downcast_ref(), Some(&OverflowError::PowerSum(2)));
+}
+
+#[test]
+fn report_covers_every_game() {
+    let games = Day2::default().parse(SAMPLE).unwrap();
+    let report = BagReport::new(&games);
+
+    assert_eq!(report.minimal_bag.amount("red"), 20);
+    assert_eq!(report.minimal_bag.amount("green"), 13);
+    assert_eq!(report.minimal_bag.amount("blue"), 15);
+
+    let red = report
+        .colors
+        .iter()
+        .find(|stats| stats.color.as_str() == "red")
+        .unwrap();
+    assert_eq!((red.draws, red.min, red.max), (12, 1, 20));
+    assert_eq!(red.mean, 63.0 / 12.0);
+
+    let lowering_red: Vec<(u32, Vec<u32>)> = report
+        .thresholds
+        .iter()
+        .filter(|threshold| threshold.color.as_str() == "red")
+        .map(|threshold| (threshold.limit, threshold.games.clone()))
+        .collect();
+    assert_eq!(
+        lowering_red,
+        [
+            (20, vec![3]),
+            (14, vec![4]),
+            (6, vec![5]),
+            (4, vec![1]),
+            (2, vec![0]),
+            (1, vec![2])
+        ]
+    );
+}
+
+#[test]
+fn report_means_do_not_overflow() {
+    let games = Day2::default()
+        .parse("Game 1: 4294967295 a\nGame 2: 4294967295 a\n")
+        .unwrap();
+    let report = BagReport::new(&games);
+
+    assert_eq!(report.colors[0].mean, 4294967295.0);
+}
+
+#[test]
+fn report_aligns_colours_by_characters() {
+    let games = Day2::default()
+        .parse("Game 1: 3 grün, 1000000 blue\nGame 2: 4 grün\n")
+        .unwrap();
+    let report = BagReport::new(&games).to_string();
+
+    assert!(report.contains(
+        "Color  Draws  Min      Mean        Max\n\
+         grün   2      3        3.50        4\n\
+         blue   1      1000000  1000000.00  1000000\n"
+    ));
+}
@@ -0,0 +1,72 @@
+//! Streams generated schematics of growing heights. The throughput staying flat across sizes shows
+//! the scan takes linear time.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day3::{stream, GearRules};
+
+const WIDTH: usize = 140;
+
+/// A schematic of `height` rows as wide as the puzzle input, with numbers, symbols and gears
+/// scattered as densely as in it.
+fn generate(height: usize) -> String {
+    // A fixed linear congruential generator, so every run streams the same schematic.
+    let mut state: u64 = 0x2023_1203;
+    let mut next = move |bound: u64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) % bound
+    };
+
+    let mut schematic = String::with_capacity((WIDTH + 1) * height);
+    for _ in 0..height {
+        let mut row = String::with_capacity(WIDTH);
+        while row.len() < WIDTH {
+            match next(10) {
+                0 | 1 => {
+                    let digits = 1 + next(3);
+                    for _ in 0..digits {
+                        row.push(char::from(b'0' + next(10) as u8));
+                    }
+                    row.push('.');
+                }
+                2 => {
+                    row.push(['*', '#', '+', '$', '/', '=', '@', '%', '-', '&'][next(10) as usize])
+                }
+                _ => row.push('.'),
+            }
+        }
+        row.truncate(WIDTH);
+        schematic.push_str(&row);
+        schematic.push('\n');
+    }
+
+    schematic
+}
+
+fn bench_stream(c: &mut Criterion) {
+    let rules = GearRules::default();
+
+    let mut group = c.benchmark_group("stream");
+    for height in [1_000, 4_000, 16_000] {
+        let schematic = generate(height);
+        group.throughput(Throughput::Bytes(schematic.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(height),
+            &schematic,
+            |b, schematic| {
+                b.iter(|| {
+                    stream(black_box(schematic.as_bytes()), &rules, |gear| {
+                        black_box(gear);
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_stream);
+criterion_main!(benches);
@@ -0,0 +1,184 @@
+use std::{fmt, str::FromStr};
+
+use serde::Serialize;
+
+use crate::{OverflowError, PartNumber, Schematic, Symbol};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GearRulesError {
+    #[error("'{0}' is not a number of gear numbers; expected N, MIN-MAX or MIN-.")]
+    InvalidCount(String),
+    #[error("The range {min}-{max} of gear numbers is empty.")]
+    EmptyRange { min: usize, max: usize },
+}
+
+/// How many numbers must touch a gear symbol for it to be a gear: exactly N, between MIN and MAX
+/// inclusive, or at least MIN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberCount {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl NumberCount {
+    pub fn exactly(count: usize) -> Self {
+        NumberCount {
+            min: count,
+            max: Some(count),
+        }
+    }
+
+    pub fn between(min: usize, max: usize) -> Self {
+        NumberCount {
+            min,
+            max: Some(max),
+        }
+    }
+
+    pub fn at_least(min: usize) -> Self {
+        NumberCount { min, max: None }
+    }
+
+    pub fn contains(&self, count: usize) -> bool {
+        count >= self.min && self.max.is_none_or(|max| count <= max)
+    }
+}
+
+/// Reads `N`, `MIN-MAX` or `MIN-`.
+impl FromStr for NumberCount {
+    type Err = GearRulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GearRulesError::InvalidCount(s.to_string());
+        let number = |s: &str| s.trim().parse::<usize>().map_err(|_| invalid());
+
+        match s.split_once('-') {
+            None => Ok(NumberCount::exactly(number(s)?)),
+            Some((min, max)) if max.trim().is_empty() => Ok(NumberCount::at_least(number(min)?)),
+            Some((min, max)) => {
+                let (min, max) = (number(min)?, number(max)?);
+                if min > max {
+                    return Err(GearRulesError::EmptyRange { min, max });
+                }
+                Ok(NumberCount::between(min, max))
+            }
+        }
+    }
+}
+
+impl fmt::Display for NumberCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.max {
+            Some(max) if max == self.min => write!(f, "{max}"),
+            Some(max) => write!(f, "{}-{max}", self.min),
+            None => write!(f, "{}-", self.min),
+        }
+    }
+}
+
+/// How the numbers of a gear combine into its ratio.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Combine {
+    #[default]
+    Product,
+    Sum,
+    Min,
+    Max,
+}
+
+impl Combine {
+    /// Combines the numbers of a gear, or gives 0 when it has none. Gives `None` if the result
+    /// does not fit in 64 bits.
+    fn apply(&self, numbers: &[u32]) -> Option<u64> {
+        if numbers.is_empty() {
+            return Some(0);
+        }
+
+        let mut numbers = numbers.iter().map(|&num| u64::from(num));
+        match self {
+            Combine::Product => numbers.try_fold(1u64, u64::checked_mul),
+            Combine::Sum => numbers.try_fold(0u64, u64::checked_add),
+            Combine::Min => numbers.min(),
+            Combine::Max => numbers.max(),
+        }
+    }
+}
+
+/// Which symbols are gears and how their ratio is computed.
+///
+/// A gear symbol touching too few or too many numbers for `count` is not a gear, and is left out
+/// of the gear ratios rather than failing the run. The default rules are the ones of the puzzle:
+/// a '*' touching exactly two numbers, whose ratio is their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRules {
+    pub symbols: Vec<char>,
+    pub count: NumberCount,
+    pub combine: Combine,
+}
+
+impl Default for GearRules {
+    fn default() -> Self {
+        GearRules {
+            symbols: vec!['*'],
+            count: NumberCount::exactly(2),
+            combine: Combine::default(),
+        }
+    }
+}
+
+/// A symbol of the schematic that is a gear under some [`GearRules`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Gear {
+    pub symbol: Symbol,
+    pub numbers: Vec<PartNumber>,
+    pub ratio: u64,
+}
+
+impl GearRules {
+    pub fn is_gear_symbol(&self, c: char) -> bool {
+        self.symbols.contains(&c)
+    }
+
+    /// The gears of `schematic`, row by row.
+    pub fn gears(&self, schematic: &Schematic) -> Result<Vec<Gear>, OverflowError> {
+        schematic
+            .symbols()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, symbol)| {
+                self.gear(*symbol, || {
+                    schematic.numbers_around(index).copied().collect()
+                })
+                .transpose()
+            })
+            .collect()
+    }
+
+    /// The gear made by `symbol` if it is one, given the numbers touching it.
+    pub(crate) fn gear(
+        &self,
+        symbol: Symbol,
+        numbers: impl FnOnce() -> Vec<PartNumber>,
+    ) -> Result<Option<Gear>, OverflowError> {
+        if !self.is_gear_symbol(symbol.ch) {
+            return Ok(None);
+        }
+
+        let numbers = numbers();
+        if !self.count.contains(numbers.len()) {
+            return Ok(None);
+        }
+
+        let values: Vec<u32> = numbers.iter().map(|n| n.value).collect();
+        let ratio = self
+            .combine
+            .apply(&values)
+            .ok_or(OverflowError::GearRatio(symbol.pos()))?;
+
+        Ok(Some(Gear {
+            symbol,
+            numbers,
+            ratio,
+        }))
+    }
+}
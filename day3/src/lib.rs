@@ -0,0 +1,70 @@
+use aoc_core::Solution;
+use stable_eyre::eyre::Report;
+
+mod gear;
+mod report;
+mod schematic;
+mod stream;
+
+pub use aoc_grid::Pos;
+pub use gear::{Combine, Gear, GearRules, GearRulesError, NumberCount};
+pub use report::{SchematicReport, SymbolCount};
+pub use schematic::{PartNumber, Schematic, Symbol};
+pub use stream::{stream, StreamTotals};
+
+/// A sum or a gear ratio too large for the answer of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OverflowError {
+    #[error("The ratio of the gear at {0} does not fit in 64 bits.")]
+    GearRatio(Pos),
+    #[error("The sum of the gear ratios does not fit in 64 bits once the gear at {0} is added.")]
+    GearRatioSum(Pos),
+    #[error(
+        "The sum of the part numbers does not fit in 64 bits once the number at {0} is added."
+    )]
+    PartNumberSum(Pos),
+}
+
+/// Adds up the part numbers of a schematic.
+pub(crate) fn add_part_number(sum: u64, number: &PartNumber) -> Result<u64, OverflowError> {
+    sum.checked_add(u64::from(number.value))
+        .ok_or(OverflowError::PartNumberSum(Pos::new(
+            number.row,
+            number.col_start,
+        )))
+}
+
+/// Adds up the ratios of the gears of a schematic.
+pub(crate) fn add_gear_ratio(sum: u64, gear: &Gear) -> Result<u64, OverflowError> {
+    sum.checked_add(gear.ratio)
+        .ok_or(OverflowError::GearRatioSum(gear.symbol.pos()))
+}
+
+/// Solves day 3, finding gears according to its [`GearRules`].
+#[derive(Debug, Clone, Default)]
+pub struct Day3 {
+    pub rules: GearRules,
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Report> {
+        Schematic::new(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        Ok(input.part_numbers().try_fold(0, add_part_number)?)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        Ok(self
+            .rules
+            .gears(input)?
+            .iter()
+            .try_fold(0, add_gear_ratio)?)
+    }
+}
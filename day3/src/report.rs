@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt::Display, fmt::Write};
+
+use aoc_core::table;
+use serde::Serialize;
+
+use crate::{Gear, GearRules, OverflowError, PartNumber, Schematic};
+
+/// How many times a symbol appears in the schematic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolCount {
+    pub symbol: char,
+    pub count: usize,
+}
+
+/// What a schematic is made of: which numbers are part numbers, which symbols it holds, and its
+/// gears. Rows and columns count from 0, as in [`Schematic`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchematicReport {
+    /// The numbers touching a symbol, row by row.
+    pub part_numbers: Vec<PartNumber>,
+    /// The numbers touching no symbol, row by row.
+    pub isolated: Vec<PartNumber>,
+    /// Every kind of symbol, in character order.
+    pub symbols: Vec<SymbolCount>,
+    /// The gears under the rules the report was made with, row by row.
+    pub gears: Vec<Gear>,
+}
+
+impl SchematicReport {
+    pub fn new(schematic: &Schematic, rules: &GearRules) -> Result<Self, OverflowError> {
+        let mut symbols: BTreeMap<char, usize> = BTreeMap::new();
+        for symbol in schematic.symbols() {
+            *symbols.entry(symbol.ch).or_default() += 1;
+        }
+
+        Ok(SchematicReport {
+            part_numbers: schematic.part_numbers().copied().collect(),
+            isolated: schematic.isolated_numbers().copied().collect(),
+            symbols: symbols
+                .into_iter()
+                .map(|(symbol, count)| SymbolCount { symbol, count })
+                .collect(),
+            gears: rules.gears(schematic)?,
+        })
+    }
+
+    /// Renders the report as CSV, one record per number, symbol kind and gear. The `kind` column
+    /// tells them apart: `value` holds the number, the symbol count or the gear ratio, and
+    /// `numbers` lists the numbers of a gear.
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("kind,symbol,value,row,col_start,col_end,numbers\n");
+
+        let numbers = [("part", &self.part_numbers), ("isolated", &self.isolated)];
+        for (kind, numbers) in numbers {
+            for n in numbers {
+                writeln!(
+                    csv,
+                    "{kind},,{},{},{},{},",
+                    n.value, n.row, n.col_start, n.col_end
+                )
+                .unwrap();
+            }
+        }
+        for symbol in &self.symbols {
+            writeln!(
+                csv,
+                "symbol,{},{},,,,",
+                csv_field(symbol.symbol),
+                symbol.count
+            )
+            .unwrap();
+        }
+        for gear in &self.gears {
+            writeln!(
+                csv,
+                "gear,{},{},{},{},{},{}",
+                csv_field(gear.symbol.ch),
+                gear.ratio,
+                gear.symbol.row,
+                gear.symbol.col,
+                gear.symbol.col + 1,
+                gear_numbers(gear, " "),
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+
+    /// Renders the report as a JSON object.
+    pub fn json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Quotes a symbol that would otherwise break the CSV record it is in.
+fn csv_field(symbol: char) -> String {
+    match symbol {
+        '"' => "\"\"\"\"".to_string(),
+        ',' => "\",\"".to_string(),
+        _ => symbol.to_string(),
+    }
+}
+
+fn gear_numbers(gear: &Gear, separator: &str) -> String {
+    let numbers: Vec<String> = gear.numbers.iter().map(|n| n.value.to_string()).collect();
+    numbers.join(separator)
+}
+
+fn number_table(numbers: &[PartNumber]) -> String {
+    let rows = numbers
+        .iter()
+        .map(|n| {
+            [
+                n.row.to_string(),
+                format!("{}..{}", n.col_start, n.col_end),
+                n.value.to_string(),
+            ]
+        })
+        .collect();
+
+    table(["Row", "Columns", "Number"], rows)
+}
+
+impl Display for SchematicReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Part numbers: {}", self.part_numbers.len())?;
+        write!(f, "{}", number_table(&self.part_numbers))?;
+        writeln!(f)?;
+
+        writeln!(f, "Isolated numbers: {}", self.isolated.len())?;
+        write!(f, "{}", number_table(&self.isolated))?;
+        writeln!(f)?;
+
+        writeln!(f, "Symbols: {}", self.symbols.len())?;
+        let rows = self
+            .symbols
+            .iter()
+            .map(|symbol| [symbol.symbol.to_string(), symbol.count.to_string()])
+            .collect();
+        write!(f, "{}", table(["Symbol", "Count"], rows))?;
+        writeln!(f)?;
+
+        writeln!(f, "Gears: {}", self.gears.len())?;
+        let rows = self
+            .gears
+            .iter()
+            .map(|gear| {
+                [
+                    gear.symbol.row.to_string(),
+                    gear.symbol.col.to_string(),
+                    gear.symbol.ch.to_string(),
+                    gear_numbers(gear, ", "),
+                    gear.ratio.to_string(),
+                ]
+            })
+            .collect();
+        write!(
+            f,
+            "{}",
+            table(["Row", "Column", "Symbol", "Numbers", "Ratio"], rows)
+        )
+    }
+}
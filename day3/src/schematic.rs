@@ -0,0 +1,174 @@
+use aoc_grid::{Grid, Pos};
+use serde::Serialize;
+use stable_eyre::eyre::{Context, Report};
+
+const BLANK: char = '.';
+
+pub(crate) fn is_special_char(c: char) -> bool {
+    !matches!(c, BLANK | '0'..='9' | '\n' | '\r')
+}
+
+/// The numbers written in the cells of a row, from left to right.
+pub(crate) fn read_numbers(row: usize, cells: &[char]) -> Result<Vec<PartNumber>, Report> {
+    let mut numbers = Vec::new();
+    let mut col = 0;
+    while col < cells.len() {
+        if !cells[col].is_ascii_digit() {
+            col += 1;
+            continue;
+        }
+
+        let col_start = col;
+        while col < cells.len() && cells[col].is_ascii_digit() {
+            col += 1;
+        }
+        let digits: String = cells[col_start..col].iter().collect();
+        let value = digits
+            .parse()
+            .wrap_err_with(|| format!("The number at {} is not valid", Pos::new(row, col_start)))?;
+        numbers.push(PartNumber {
+            value,
+            row,
+            col_start,
+            col_end: col,
+        });
+    }
+
+    Ok(numbers)
+}
+
+/// A number written in the schematic. Rows and columns count from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PartNumber {
+    pub value: u32,
+    pub row: usize,
+    pub col_start: usize,
+    /// The column just after the last digit.
+    pub col_end: usize,
+}
+
+impl PartNumber {
+    /// The positions of the digits of the number.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (self.col_start..self.col_end).map(|col| Pos::new(self.row, col))
+    }
+}
+
+/// A symbol of the schematic: any character other than a digit or a '.'. Rows and columns count
+/// from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Symbol {
+    pub ch: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Symbol {
+    pub fn pos(&self) -> Pos {
+        Pos::new(self.row, self.col)
+    }
+}
+
+/// An engine schematic, read as the numbers and symbols it contains and which of them touch,
+/// diagonals included.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: Vec<Symbol>,
+    /// The symbols touching each number, as indices into `symbols`.
+    number_symbols: Vec<Vec<usize>>,
+    /// The numbers touching each symbol, as indices into `numbers`.
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn new(input: &str) -> Result<Self, Report> {
+        let grid: Grid<char> = input.parse()?;
+
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        for (row, cells) in grid.rows().enumerate() {
+            numbers.extend(read_numbers(row, cells)?);
+            symbols.extend(
+                (0..cells.len())
+                    .filter(|&col| is_special_char(cells[col]))
+                    .map(|col| Symbol {
+                        ch: cells[col],
+                        row,
+                        col,
+                    }),
+            );
+        }
+
+        let mut symbol_at = grid.map(|_| None);
+        for (index, symbol) in symbols.iter().enumerate() {
+            symbol_at[symbol.pos()] = Some(index);
+        }
+
+        let mut number_symbols = Vec::with_capacity(numbers.len());
+        let mut symbol_numbers = vec![Vec::new(); symbols.len()];
+        for (index, number) in numbers.iter().enumerate() {
+            let mut touching: Vec<usize> = number
+                .positions()
+                .flat_map(|pos| grid.neighbours8(pos))
+                .filter_map(|pos| symbol_at[pos])
+                .collect();
+            touching.sort_unstable();
+            touching.dedup();
+
+            for &symbol in &touching {
+                symbol_numbers[symbol].push(index);
+            }
+            number_symbols.push(touching);
+        }
+
+        Ok(Schematic {
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+
+    /// Every number of the schematic, row by row.
+    pub fn numbers(&self) -> &[PartNumber] {
+        &self.numbers
+    }
+
+    /// Every symbol of the schematic, row by row.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The symbols touching the number at `index` in [`Schematic::numbers`].
+    pub fn symbols_around(&self, index: usize) -> impl Iterator<Item = &Symbol> {
+        self.number_symbols[index]
+            .iter()
+            .map(|&symbol| &self.symbols[symbol])
+    }
+
+    /// The numbers touching the symbol at `index` in [`Schematic::symbols`].
+    pub fn numbers_around(&self, index: usize) -> impl Iterator<Item = &PartNumber> {
+        self.symbol_numbers[index]
+            .iter()
+            .map(|&number| &self.numbers[number])
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        self.numbers_where(|symbols| !symbols.is_empty())
+    }
+
+    /// The numbers touching no symbol.
+    pub fn isolated_numbers(&self) -> impl Iterator<Item = &PartNumber> {
+        self.numbers_where(|symbols| symbols.is_empty())
+    }
+
+    fn numbers_where(&self, keep: impl Fn(&[usize]) -> bool) -> impl Iterator<Item = &PartNumber> {
+        self.numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(move |(_, symbols)| keep(symbols))
+            .map(|(number, _)| number)
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::BufRead;
+
+use aoc_grid::GridError;
+use stable_eyre::eyre::{Context, Report};
+
+use crate::{
+    add_gear_ratio, add_part_number,
+    schematic::{is_special_char, read_numbers},
+    Gear, GearRules, OverflowError, PartNumber, Symbol,
+};
+
+/// The answers of a schematic scanned with [`stream`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamTotals {
+    pub part_number_sum: u64,
+    pub gear_ratio_sum: u64,
+}
+
+#[derive(Debug)]
+struct Row {
+    index: usize,
+    cells: Vec<char>,
+    /// Sorted by column, as read from left to right.
+    numbers: Vec<PartNumber>,
+}
+
+impl Row {
+    fn has_symbol(&self, col: usize) -> bool {
+        self.cells.get(col).is_some_and(|&c| is_special_char(c))
+    }
+
+    /// The numbers of the row touching `col`, diagonals included.
+    fn numbers_touching(&self, col: usize) -> impl Iterator<Item = &PartNumber> {
+        // Numbers never overlap, so those touching the column are the last ones starting no
+        // further right than the column after it.
+        let end = self.numbers.partition_point(|n| n.col_start <= col + 1);
+        let start = self.numbers[..end]
+            .iter()
+            .rposition(|n| n.col_end < col)
+            .map_or(0, |index| index + 1);
+        self.numbers[start..end].iter()
+    }
+}
+
+/// Scans a schematic row by row, keeping only the rows above and below the one being looked at.
+///
+/// A row is done with once the row after it is read: its part numbers are added up and its gears
+/// are handed to `on_gear`, in order. Memory use thus only grows with the width of the schematic,
+/// not its height, so schematics too large to be read whole can be solved.
+pub fn stream(
+    reader: impl BufRead,
+    rules: &GearRules,
+    mut on_gear: impl FnMut(Gear),
+) -> Result<StreamTotals, Report> {
+    let mut totals = StreamTotals::default();
+    let mut above: Option<Row> = None;
+    let mut current: Option<Row> = None;
+
+    let mut width = None;
+    let mut blank_line = None;
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.wrap_err_with(|| format!("Failed to read line {}", index + 1))?;
+        let cells: Vec<char> = line.chars().collect();
+
+        // Blank lines may only end the schematic, as for a grid read whole.
+        if cells.is_empty() {
+            blank_line.get_or_insert(index + 1);
+            continue;
+        }
+        let expected = *width.get_or_insert(cells.len());
+        if let Some(line) = blank_line {
+            Err(GridError::Ragged {
+                line,
+                expected,
+                found: 0,
+            })?;
+        }
+        if cells.len() != expected {
+            Err(GridError::Ragged {
+                line: index + 1,
+                expected,
+                found: cells.len(),
+            })?;
+        }
+
+        let below = Row {
+            index,
+            numbers: read_numbers(index, &cells)?,
+            cells,
+        };
+        if let Some(current) = &current {
+            let window = [above.as_ref(), Some(current), Some(&below)];
+            finish_row(current, window, rules, &mut totals, &mut on_gear)?;
+        }
+        above = current.replace(below);
+    }
+
+    if let Some(current) = &current {
+        let window = [above.as_ref(), Some(current), None];
+        finish_row(current, window, rules, &mut totals, &mut on_gear)?;
+    }
+
+    Ok(totals)
+}
+
+/// Adds up the part numbers and emits the gears of `row`, given the rows around it.
+fn finish_row(
+    row: &Row,
+    window: [Option<&Row>; 3],
+    rules: &GearRules,
+    totals: &mut StreamTotals,
+    on_gear: &mut impl FnMut(Gear),
+) -> Result<(), OverflowError> {
+    let window = window.into_iter().flatten();
+
+    for number in &row.numbers {
+        let touches_symbol = (number.col_start.saturating_sub(1)..=number.col_end)
+            .any(|col| window.clone().any(|row| row.has_symbol(col)));
+        if touches_symbol {
+            totals.part_number_sum = add_part_number(totals.part_number_sum, number)?;
+        }
+    }
+
+    for (col, &ch) in row.cells.iter().enumerate() {
+        // Only symbols can be gears, as for a schematic read whole.
+        if !is_special_char(ch) {
+            continue;
+        }
+        let symbol = Symbol {
+            ch,
+            row: row.index,
+            col,
+        };
+        let gear = rules.gear(symbol, || {
+            window
+                .clone()
+                .flat_map(|row| row.numbers_touching(col))
+                .copied()
+                .collect()
+        })?;
+        if let Some(gear) = gear {
+            totals.gear_ratio_sum = add_gear_ratio(totals.gear_ratio_sum, &gear)?;
+            on_gear(gear);
+        }
+    }
+
+    Ok(())
+}
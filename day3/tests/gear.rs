@@ -0,0 +1,67 @@
+use aoc_core::Solution;
+use day3::{Combine, Day3, GearRules, GearRulesError, NumberCount, OverflowError, Pos};
+
+const CROWDED: &str = "2.3\n.*.\n4.5\n\n";
+
+#[test]
+fn crowded_gears_are_skipped_by_default() {
+    let day = Day3::default();
+    let input = day.parse(CROWDED).unwrap();
+
+    assert_eq!(day.part2(&input).unwrap(), 0);
+}
+
+#[test]
+fn rules_choose_the_gears_and_their_ratio() {
+    let day = Day3 {
+        rules: GearRules {
+            symbols: vec!['*', '#'],
+            count: NumberCount::at_least(2),
+            combine: Combine::Sum,
+        },
+    };
+    let input = day.parse(CROWDED).unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 2 + 3 + 4 + 5);
+
+    let input = day.parse("1#2\n...\n3*4\n").unwrap();
+    let gears = day.rules.gears(&input).unwrap();
+    assert_eq!(gears.len(), 2);
+    assert_eq!((gears[0].symbol.ch, gears[0].ratio), ('#', 3));
+    assert_eq!((gears[1].symbol.ch, gears[1].ratio), ('*', 7));
+}
+
+#[test]
+fn number_counts_parse_from_text() {
+    assert_eq!("2".parse(), Ok(NumberCount::exactly(2)));
+    assert_eq!("2-4".parse(), Ok(NumberCount::between(2, 4)));
+    assert_eq!("3-".parse(), Ok(NumberCount::at_least(3)));
+    assert_eq!(
+        "4-2".parse::<NumberCount>(),
+        Err(GearRulesError::EmptyRange { min: 4, max: 2 })
+    );
+    assert!("two".parse::<NumberCount>().is_err());
+    assert_eq!(NumberCount::at_least(3).to_string(), "3-");
+}
+
+#[test]
+fn overflowing_ratios_name_their_gear() {
+    let day = Day3 {
+        rules: GearRules {
+            count: NumberCount::at_least(2),
+            ..GearRules::default()
+        },
+    };
+
+    // The product of two numbers always fits, unlike that of three.
+    let input = day.parse("4000000000*4000000000\n").unwrap();
+    assert_eq!(day.part2(&input).unwrap(), 16_000_000_000_000_000_000);
+
+    let input = day
+        .parse("4000000000*4000000000\n.........4000000000..\n")
+        .unwrap();
+    let error = day.part2(&input).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<OverflowError>(),
+        Some(&OverflowError::GearRatio(Pos::new(0, 10)))
+    );
+}
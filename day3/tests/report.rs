@@ -0,0 +1,48 @@
+use aoc_core::Solution;
+use day3::{Day3, SchematicReport, SymbolCount};
+
+const SAMPLE: &str = include_str!("../1_sample.txt");
+
+#[test]
+fn report_sorts_numbers_and_counts_symbols() {
+    let day = Day3::default();
+    let report = SchematicReport::new(&day.parse(SAMPLE).unwrap(), &day.rules).unwrap();
+
+    assert_eq!(report.part_numbers.len(), 8);
+    let isolated: Vec<u32> = report.isolated.iter().map(|n| n.value).collect();
+    assert_eq!(isolated, [114, 58]);
+    assert_eq!(
+        report.symbols[2],
+        SymbolCount {
+            symbol: '*',
+            count: 3
+        }
+    );
+
+    let gears: Vec<(u32, u32)> = report
+        .gears
+        .iter()
+        .map(|gear| (gear.numbers[0].value, gear.numbers[1].value))
+        .collect();
+    assert_eq!(gears, [(467, 35), (755, 598)]);
+}
+
+#[test]
+fn report_renders_as_csv_and_json() {
+    let day = Day3::default();
+    let report = SchematicReport::new(&day.parse("1*2\n,..\n").unwrap(), &day.rules).unwrap();
+
+    assert_eq!(
+        report.csv(),
+        "kind,symbol,value,row,col_start,col_end,numbers\n\
+         part,,1,0,0,1,\n\
+         part,,2,0,2,3,\n\
+         symbol,*,1,,,,\n\
+         symbol,\",\",1,,,,\n\
+         gear,*,2,0,1,2,1 2\n"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&report.json().unwrap()).unwrap();
+    assert_eq!(json["gears"][0]["ratio"], 2);
+    assert_eq!(json["isolated"].as_array().unwrap().len(), 0);
+}
@@ -0,0 +1,68 @@
+use day3::{PartNumber, Schematic, Symbol};
+
+const SAMPLE: &str = "467..114..\n...*......\n..35..633.\n";
+
+#[test]
+fn extracts_numbers_and_symbols_without_altering_them() {
+    let schematic = Schematic::new(SAMPLE).unwrap();
+
+    assert_eq!(
+        schematic.numbers(),
+        [
+            PartNumber {
+                value: 467,
+                row: 0,
+                col_start: 0,
+                col_end: 3
+            },
+            PartNumber {
+                value: 114,
+                row: 0,
+                col_start: 5,
+                col_end: 8
+            },
+            PartNumber {
+                value: 35,
+                row: 2,
+                col_start: 2,
+                col_end: 4
+            },
+            PartNumber {
+                value: 633,
+                row: 2,
+                col_start: 6,
+                col_end: 9
+            },
+        ]
+    );
+    assert_eq!(
+        schematic.symbols(),
+        [Symbol {
+            ch: '*',
+            row: 1,
+            col: 3
+        }]
+    );
+}
+
+#[test]
+fn relates_numbers_to_the_symbols_they_touch() {
+    let schematic = Schematic::new(SAMPLE).unwrap();
+
+    let around: Vec<u32> = schematic.numbers_around(0).map(|n| n.value).collect();
+    assert_eq!(around, [467, 35]);
+    assert_eq!(schematic.symbols_around(1).count(), 0);
+
+    let parts: Vec<u32> = schematic.part_numbers().map(|n| n.value).collect();
+    assert_eq!(parts, [467, 35]);
+}
+
+#[test]
+fn numbers_touching_two_symbols_belong_to_both() {
+    let schematic = Schematic::new("*..\n.5.\n..*\n").unwrap();
+
+    assert_eq!(schematic.symbols_around(0).count(), 2);
+    assert_eq!(schematic.numbers_around(0).count(), 1);
+    assert_eq!(schematic.numbers_around(1).count(), 1);
+    assert_eq!(schematic.part_numbers().count(), 1);
+}
@@ -0,0 +1,71 @@
+use aoc_core::Solution;
+use day3::{stream, Day3, GearRules, NumberCount, StreamTotals};
+
+fn totals(input: &str, rules: &GearRules) -> (StreamTotals, Vec<day3::Gear>) {
+    let mut gears = Vec::new();
+    let totals = stream(input.as_bytes(), rules, |gear| gears.push(gear)).unwrap();
+    (totals, gears)
+}
+
+#[test]
+fn streaming_matches_reading_the_schematic_whole() {
+    let inputs = [
+        include_str!("../1_sample.txt"),
+        include_str!("../1_full.txt"),
+        include_str!("../borders.txt"),
+        include_str!("../single_line.txt"),
+        include_str!("../empty.txt"),
+    ];
+    let rules = GearRules {
+        count: NumberCount::at_least(1),
+        ..GearRules::default()
+    };
+
+    for input in inputs {
+        let day = Day3 {
+            rules: rules.clone(),
+        };
+        let schematic = day.parse(input).unwrap();
+        let (totals, gears) = totals(input, &rules);
+
+        assert_eq!(totals.part_number_sum, day.part1(&schematic).unwrap());
+        assert_eq!(totals.gear_ratio_sum, day.part2(&schematic).unwrap());
+        assert_eq!(gears, rules.gears(&schematic).unwrap());
+    }
+}
+
+#[test]
+fn only_symbols_are_gears_when_streaming() {
+    let input = "12.\n.*.\n3.4\n";
+    let rules = GearRules {
+        symbols: vec!['*', '.', '1'],
+        count: NumberCount::at_least(1),
+        ..GearRules::default()
+    };
+    let day = Day3 {
+        rules: rules.clone(),
+    };
+    let schematic = day.parse(input).unwrap();
+    let (totals, gears) = totals(input, &rules);
+
+    assert_eq!(gears.len(), 1);
+    assert_eq!(gears, rules.gears(&schematic).unwrap());
+    assert_eq!(totals.gear_ratio_sum, day.part2(&schematic).unwrap());
+}
+
+#[test]
+fn gears_are_emitted_row_by_row() {
+    let (totals, gears) = totals("1*1\n...\n2*2\n...\n3*3\n", &GearRules::default());
+
+    let ratios: Vec<u64> = gears.iter().map(|gear| gear.ratio).collect();
+    assert_eq!(ratios, [1, 4, 9]);
+    assert_eq!(totals.gear_ratio_sum, 14);
+}
+
+#[test]
+fn blank_lines_may_only_end_the_schematic() {
+    let rules = GearRules::default();
+    assert!(stream("1*1\n\n\n".as_bytes(), &rules, |_| {}).is_ok());
+    assert!(stream("1*1\n\n2*2\n".as_bytes(), &rules, |_| {}).is_err());
+    assert!(stream("1*1\n2*\n".as_bytes(), &rules, |_| {}).is_err());
+}
@@ -0,0 +1,67 @@
+//! Compares counting the winning numbers of a card by scanning the list of winning numbers for each
+//! of its numbers against looking them up in the set a card builds when it is made.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day4::Card;
+
+const CARDS: usize = 200;
+const WINNING: usize = 300;
+const NUMBERS: usize = 500;
+
+/// Cards with hundreds of numbers each, drawn between 1 and 9999 so that only a dozen or so win.
+fn generate() -> Vec<(Vec<u32>, Vec<u32>)> {
+    // A fixed linear congruential generator, so every run compares the same cards.
+    let mut state: u64 = 0x2023_1204;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        1 + ((state >> 33) % 9999) as u32
+    };
+
+    (0..CARDS)
+        .map(|_| {
+            let winning = (0..WINNING).map(|_| next()).collect();
+            let numbers = (0..NUMBERS).map(|_| next()).collect();
+            (winning, numbers)
+        })
+        .collect()
+}
+
+/// Points and pairs as computed before cards kept their matches, scanning the numbers twice.
+fn scan(cards: &[(Vec<u32>, Vec<u32>)]) -> (u64, u64) {
+    cards
+        .iter()
+        .fold((0, 0), |(points, pairs), (winning, numbers)| {
+            let card_pairs = numbers.iter().filter(|num| winning.contains(num)).count() as u64;
+            let card_points = numbers
+                .iter()
+                .filter(|num| winning.contains(num))
+                .fold(0u64, |points, _| if points == 0 { 1 } else { points * 2 });
+            (points + card_points, pairs + card_pairs)
+        })
+}
+
+fn set(cards: &[(Vec<u32>, Vec<u32>)]) -> (u64, u64) {
+    cards
+        .iter()
+        .fold((0, 0), |(points, pairs), (winning, numbers)| {
+            let card = Card::new(0, winning.clone(), numbers.clone());
+            (points + card.points().unwrap(), pairs + card.pairs() as u64)
+        })
+}
+
+fn bench_matching(c: &mut Criterion) {
+    let cards = generate();
+    assert_eq!(scan(&cards).1, set(&cards).1);
+
+    let mut group = c.benchmark_group("generated_cards");
+    group.bench_function("vec_contains", |b| b.iter(|| scan(black_box(&cards))));
+    group.bench_function("card", |b| b.iter(|| set(black_box(&cards))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_matching);
+criterion_main!(benches);
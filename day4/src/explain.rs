@@ -0,0 +1,92 @@
+use std::fmt::Write;
+
+use aoc_core::table;
+
+use crate::{CardTable, OverflowError};
+
+/// How a card of a [`CardTable`] scores and which copies it wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardExplanation {
+    pub id: u32,
+    /// How many of its numbers are winning numbers.
+    pub matches: u32,
+    pub points: u64,
+    /// How many copies of the card are held, original included.
+    pub copies: u64,
+    /// The ids of the cards it wins a copy of, once for every copy of it held.
+    pub grants: Vec<u32>,
+}
+
+impl CardExplanation {
+    /// Renders explanations as a table with aligned columns.
+    pub fn table(explanations: &[CardExplanation]) -> String {
+        let header = ["Card", "Matches", "Points", "Copies", "Grants"];
+        let rows: Vec<[String; 5]> = explanations
+            .iter()
+            .map(|card| {
+                let grants: Vec<String> = card.grants.iter().map(u32::to_string).collect();
+                [
+                    card.id.to_string(),
+                    card.matches.to_string(),
+                    card.points.to_string(),
+                    card.copies.to_string(),
+                    match grants.is_empty() {
+                        true => "-".to_string(),
+                        false => grants.join(", "),
+                    },
+                ]
+            })
+            .collect();
+
+        table(header, rows)
+    }
+}
+
+impl CardTable {
+    /// Explains how every card scores and what it wins, in the order of the cards.
+    pub fn explain(&self) -> Result<Vec<CardExplanation>, OverflowError> {
+        self.iter()
+            .enumerate()
+            .map(|(index, (card, copies))| {
+                Ok(CardExplanation {
+                    id: card.id(),
+                    matches: card.pairs(),
+                    points: card.points()?,
+                    copies,
+                    grants: self.granted(index).map(|granted| granted.id()).collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// Renders which cards win copies of which as a Graphviz graph. Every edge is labelled and
+    /// weighted with the copies won through it, which are the copies held of the card it starts
+    /// from.
+    pub fn dot(&self) -> String {
+        let mut dot = String::from("digraph scratchcards {\n    node [shape=box];\n");
+
+        for (index, (card, copies)) in self.iter().enumerate() {
+            writeln!(
+                dot,
+                "    card{index} [label=\"Card {}\\n{} matches, {copies} copies\"];",
+                card.id(),
+                card.pairs()
+            )
+            .unwrap();
+        }
+        for (index, (_, copies)) in self.iter().enumerate() {
+            // Graphviz reads weights as C ints.
+            let weight = copies.min(i32::MAX as u64);
+            for granted in index + 1..index + 1 + self.granted(index).count() {
+                writeln!(
+                    dot,
+                    "    card{index} -> card{granted} [label=\"{copies}\", weight={weight}];"
+                )
+                .unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
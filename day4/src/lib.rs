@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse_lines, parse_lines_recovering, Diagnostic, Solution};
+use aoc_parse::{end, keyword, list_of, separator, skip_whitespace, unsigned, Cursor, ParseError};
+use stable_eyre::eyre::{eyre, Report};
+
+mod explain;
+mod table;
+mod validate;
+
+pub use explain::CardExplanation;
+pub use table::CardTable;
+pub use validate::{validate, CardIssue, Validation, ValidationError};
+
+/// A number of points or copies too large for the answer of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OverflowError {
+    #[error("The points of card {0} do not fit in 64 bits.")]
+    Points(u32),
+    #[error("The sum of the points does not fit in 64 bits once card {0} is added.")]
+    PointsSum(u32),
+    #[error("The copies of card {0} do not fit in 64 bits.")]
+    Copies(u32),
+    #[error(
+        "The total number of cards does not fit in 64 bits once the copies of card {0} are added."
+    )]
+    Total(u32),
+}
+
+#[derive(Debug)]
+enum Token {
+    Card(u32),
+    Number(u32),
+    Separator,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TokenizationError {
+    #[error("Expected a declaration of a card (+Card+ <number>:), but failed to find it.")]
+    ExpectedCardDeclaration(#[source] ParseError),
+    #[error("Expected a number for the card (Card +<number>+:), but failed to find it.")]
+    ExpectedCardNumber(#[source] ParseError),
+    #[error("Expected a separator after the card declaration (Card <number>+:+), but failed to find it.")]
+    ExpectedCardSeparator(#[source] ParseError),
+    #[error("Expected a list of numbers (Card <number>: +<numbers>+ | +<numbers>+), but failed to find it.")]
+    ExpectedNumbers(#[source] ParseError),
+    #[error("Expected a separator between numbers (<winning numbers> +|+ <card number>), but failed to find it.")]
+    ExpectedInputSeparator(#[source] ParseError),
+    #[error("Expected the card to end after its numbers, but found more input.")]
+    TrailingInput(#[source] ParseError),
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ParsingError {
+    #[error("Expected a card, but failed to find it.")]
+    ExpectedCardToken,
+    #[error("Expected a number, but failed to find it.")]
+    ExpectedNumberToken,
+}
+
+fn next_numbers(input: &mut Cursor<'_>) -> Result<Vec<Token>, Report> {
+    let numbers = list_of(input, unsigned, |input| {
+        skip_whitespace(input);
+        Ok(())
+    })
+    .map_err(TokenizationError::ExpectedNumbers)?;
+
+    Ok(numbers.into_iter().map(Token::Number).collect())
+}
+
+fn next_card(input: &mut Cursor<'_>) -> Result<Token, Report> {
+    keyword(input, "Card").map_err(TokenizationError::ExpectedCardDeclaration)?;
+    skip_whitespace(input);
+    let num = unsigned(input).map_err(TokenizationError::ExpectedCardNumber)?;
+    keyword(input, ":").map_err(TokenizationError::ExpectedCardSeparator)?;
+
+    Ok(Token::Card(num))
+}
+
+fn next_separator(input: &mut Cursor<'_>) -> Result<Token, Report> {
+    separator(input, &['|']).map_err(TokenizationError::ExpectedInputSeparator)?;
+
+    Ok(Token::Separator)
+}
+
+fn tokenize_line(line: &str) -> Result<Vec<Token>, Report> {
+    let mut result = vec![];
+    let mut stream = Cursor::new(line);
+
+    result.push(next_card(&mut stream)?);
+    skip_whitespace(&mut stream);
+
+    result.extend(next_numbers(&mut stream)?);
+    skip_whitespace(&mut stream);
+
+    result.push(next_separator(&mut stream)?);
+    skip_whitespace(&mut stream);
+
+    result.extend(next_numbers(&mut stream)?);
+    skip_whitespace(&mut stream);
+
+    end(&mut stream).map_err(TokenizationError::TrailingInput)?;
+
+    Ok(result)
+}
+
+/// The winning numbers of a card, for quick lookups.
+///
+/// They are kept in a bitset when it takes no more words than there are winning numbers, so a
+/// card costs memory in proportion to its numbers rather than to the largest of them.
+#[derive(Debug, Clone)]
+enum WinningSet {
+    Bits(Vec<u64>),
+    Hashed(HashSet<u32>),
+}
+
+impl WinningSet {
+    fn new(numbers: &[u32]) -> Self {
+        match numbers.iter().max() {
+            Some(&max) if max as usize / 64 >= numbers.len() => {
+                WinningSet::Hashed(numbers.iter().copied().collect())
+            }
+            max => {
+                let mut bits = vec![0; max.map_or(0, |&max| max as usize / 64 + 1)];
+                for &num in numbers {
+                    bits[num as usize / 64] |= 1 << (num % 64);
+                }
+                WinningSet::Bits(bits)
+            }
+        }
+    }
+
+    fn contains(&self, num: u32) -> bool {
+        match self {
+            WinningSet::Bits(bits) => bits
+                .get(num as usize / 64)
+                .is_some_and(|word| word & (1 << (num % 64)) != 0),
+            WinningSet::Hashed(numbers) => numbers.contains(&num),
+        }
+    }
+}
+
+/// A scratchcard. How many of its numbers are winning numbers is counted once, when the card is
+/// made.
+#[derive(Debug, Clone)]
+pub struct Card {
+    card_id: u32,
+    winning_nums: Vec<u32>,
+    card_nums: Vec<u32>,
+    winning: WinningSet,
+    pairs: u32,
+}
+
+impl Card {
+    pub fn new(card_id: u32, winning_nums: Vec<u32>, card_nums: Vec<u32>) -> Self {
+        let winning = WinningSet::new(&winning_nums);
+        let pairs = card_nums
+            .iter()
+            .filter(|&&num| winning.contains(num))
+            .count() as u32;
+
+        Card {
+            card_id,
+            winning_nums,
+            card_nums,
+            winning,
+            pairs,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.card_id
+    }
+
+    /// The winning numbers, in the order they are written on the card.
+    pub fn winning_numbers(&self) -> &[u32] {
+        &self.winning_nums
+    }
+
+    /// The numbers the card holds, in the order they are written on it.
+    pub fn numbers(&self) -> &[u32] {
+        &self.card_nums
+    }
+
+    pub fn is_winning(&self, num: u32) -> bool {
+        self.winning.contains(num)
+    }
+
+    /// How many of the numbers of the card are winning numbers.
+    pub fn pairs(&self) -> u32 {
+        self.pairs
+    }
+
+    /// 1 point for the first winning number, doubled for every other one.
+    pub fn points(&self) -> Result<u64, OverflowError> {
+        match self.pairs {
+            0 => Ok(0),
+            pairs => 1u64
+                .checked_shl(pairs - 1)
+                .ok_or(OverflowError::Points(self.card_id)),
+        }
+    }
+}
+
+fn parse_card(mut input: impl Iterator<Item = Token>) -> Result<Card, Report> {
+    let card_id = if let Some(Token::Card(num)) = input.next() {
+        num
+    } else {
+        return Err(eyre!(ParsingError::ExpectedCardToken));
+    };
+
+    let mut winning_nums = vec![];
+    let mut card_nums = vec![];
+    loop {
+        let num = match input.next() {
+            Some(Token::Number(num)) => num,
+            Some(Token::Separator) => break,
+            Some(_) | None => return Err(eyre!(ParsingError::ExpectedNumberToken)),
+        };
+
+        winning_nums.push(num);
+    }
+
+    loop {
+        let num = match input.next() {
+            Some(Token::Number(num)) => num,
+            Some(_) => return Err(eyre!(ParsingError::ExpectedNumberToken)),
+            None => break,
+        };
+
+        card_nums.push(num);
+    }
+
+    Ok(Card::new(card_id, winning_nums, card_nums))
+}
+
+fn parse_line(line: &str) -> Result<Card, Report> {
+    let tokens = tokenize_line(line)?;
+    parse_card(tokens.into_iter())
+}
+
+/// Solves day 4, refusing piles of cards with [`CardIssue`]s if its [`Validation`] says so.
+#[derive(Debug, Clone, Default)]
+pub struct Day4 {
+    pub validation: Validation,
+}
+
+impl Day4 {
+    fn table(&self, cards: Vec<Card>) -> Result<CardTable, Report> {
+        let table = CardTable::new(cards)?;
+        if self.validation == Validation::Deny {
+            let issues = table.issues();
+            if !issues.is_empty() {
+                return Err(ValidationError(issues).into());
+            }
+        }
+
+        Ok(table)
+    }
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = CardTable;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, Report> {
+        self.table(parse_lines(input, parse_line)?)
+    }
+
+    fn parse_recovering(&self, input: &str) -> Result<(Self::Input, Vec<Diagnostic>), Report> {
+        let (cards, skipped) = parse_lines_recovering(input, parse_line);
+        Ok((self.table(cards)?, skipped))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        let mut sum: u64 = 0;
+        for card in input.cards() {
+            sum = sum
+                .checked_add(card.points()?)
+                .ok_or(OverflowError::PointsSum(card.id()))?;
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Answer, Report> {
+        Ok(input.total())
+    }
+}
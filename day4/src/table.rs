@@ -0,0 +1,80 @@
+use crate::{validate, Card, CardIssue, OverflowError};
+
+/// The scratchcards of a pile, in order, along with how many copies of each are won.
+///
+/// A card with N pairs wins a copy of each of the N cards following it in the pile, for every
+/// copy of it held. Cards past the end of the pile do not exist, so they are never won.
+#[derive(Debug, Clone)]
+pub struct CardTable {
+    cards: Vec<Card>,
+    copies: Vec<u64>,
+    total: u64,
+}
+
+impl CardTable {
+    /// Fails if a card is won more times than fit in 64 bits, or the pile grows that large.
+    pub fn new(cards: Vec<Card>) -> Result<Self, OverflowError> {
+        let mut copies = vec![0; cards.len()];
+        let mut total: u64 = 0;
+
+        // Every card adds its copies to a range of the cards after it. Instead of adding them to
+        // each card of the range, `changes` records where the range starts and ends, and the
+        // copies won by a card are the running sum of the changes up to it. As every card holds
+        // fewer than 2^64 copies, the changes always fit in an i128.
+        let mut changes = vec![0i128; cards.len() + 1];
+        let mut won: i128 = 0;
+        for (index, card) in cards.iter().enumerate() {
+            won += changes[index];
+            copies[index] = u64::try_from(won + 1).map_err(|_| OverflowError::Copies(card.id()))?;
+            total = total
+                .checked_add(copies[index])
+                .ok_or(OverflowError::Total(card.id()))?;
+
+            let end = granted_end(&cards, index);
+            if index + 1 < end {
+                changes[index + 1] += i128::from(copies[index]);
+                changes[end] -= i128::from(copies[index]);
+            }
+        }
+
+        Ok(CardTable {
+            cards,
+            copies,
+            total,
+        })
+    }
+
+    /// The cards won by the card at `index`, once for every copy of it held.
+    pub(crate) fn granted(&self, index: usize) -> impl Iterator<Item = &Card> {
+        self.cards[index + 1..granted_end(&self.cards, index)].iter()
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// How many copies of each card are held, original included, in the order of the cards.
+    pub fn copies(&self) -> &[u64] {
+        &self.copies
+    }
+
+    /// Every card alongside how many copies of it are held.
+    pub fn iter(&self) -> impl Iterator<Item = (&Card, u64)> {
+        self.cards.iter().zip(self.copies.iter().copied())
+    }
+
+    /// What about the pile the copy rules do not expect.
+    pub fn issues(&self) -> Vec<CardIssue> {
+        validate(&self.cards)
+    }
+
+    /// How many cards are held in total, originals included.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// The index right after the last card won by the card at `index`.
+fn granted_end(cards: &[Card], index: usize) -> usize {
+    (index + 1 + cards[index].pairs() as usize).min(cards.len())
+}
@@ -0,0 +1,123 @@
+use std::{collections::HashSet, fmt};
+
+use crate::Card;
+
+/// Something about a pile of cards that the puzzle does not expect: the copy rules of part 2
+/// assume the cards are numbered 1, 2, 3 and so on, and a number held twice by a card matches
+/// twice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardIssue {
+    /// A card uses the id of a card before it. `position` counts the cards of the pile from 1.
+    DuplicateId { id: u32, position: usize },
+    /// A card has a lower id than the card right before it.
+    OutOfOrder { previous: u32, id: u32 },
+    /// No card has the ids from `first` to `last`, although a card with a higher id exists.
+    MissingIds { first: u32, last: u32 },
+    /// A winning number is written more than once on the card.
+    RepeatedWinningNumber { id: u32, number: u32 },
+    /// One of the numbers the card holds is written more than once on it.
+    RepeatedNumber { id: u32, number: u32 },
+}
+
+impl fmt::Display for CardIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardIssue::DuplicateId { id, position } => {
+                write!(
+                    f,
+                    "Card {id} appears again as card number {position} of the pile."
+                )
+            }
+            CardIssue::OutOfOrder { previous, id } => {
+                write!(f, "Card {id} comes after card {previous}.")
+            }
+            CardIssue::MissingIds { first, last } if first == last => {
+                write!(f, "Card {first} is missing.")
+            }
+            CardIssue::MissingIds { first, last } => {
+                write!(f, "Cards {first} to {last} are missing.")
+            }
+            CardIssue::RepeatedWinningNumber { id, number } => {
+                write!(
+                    f,
+                    "Card {id} lists {number} as a winning number more than once."
+                )
+            }
+            CardIssue::RepeatedNumber { id, number } => {
+                write!(f, "Card {id} holds the number {number} more than once.")
+            }
+        }
+    }
+}
+
+/// Whether a pile with [`CardIssue`]s can still be solved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Validation {
+    /// Solve the pile anyway, leaving it to the caller to report the issues.
+    #[default]
+    Warn,
+    /// Refuse to solve the pile.
+    Deny,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Found {} issue(s) with the cards:\n{}", .0.len(), list(.0))]
+pub struct ValidationError(pub Vec<CardIssue>);
+
+fn list(issues: &[CardIssue]) -> String {
+    let lines: Vec<String> = issues.iter().map(|issue| format!("  - {issue}")).collect();
+    lines.join("\n")
+}
+
+/// The issues of a pile of cards, in the order of the pile, followed by the ids missing from it.
+pub fn validate(cards: &[Card]) -> Vec<CardIssue> {
+    let mut issues = Vec::new();
+    let mut seen = HashSet::new();
+    let mut previous = None;
+
+    for (index, card) in cards.iter().enumerate() {
+        let id = card.id();
+        if !seen.insert(id) {
+            issues.push(CardIssue::DuplicateId {
+                id,
+                position: index + 1,
+            });
+        } else if let Some(previous) = previous.filter(|&previous| id < previous) {
+            issues.push(CardIssue::OutOfOrder { previous, id });
+        }
+        previous = Some(id);
+
+        issues.extend(
+            repeated(card.winning_numbers())
+                .map(|number| CardIssue::RepeatedWinningNumber { id, number }),
+        );
+        issues.extend(
+            repeated(card.numbers()).map(|number| CardIssue::RepeatedNumber { id, number }),
+        );
+    }
+
+    let mut ids: Vec<u32> = seen.into_iter().collect();
+    ids.sort_unstable();
+    let mut expected = 1;
+    for id in ids {
+        if id > expected {
+            issues.push(CardIssue::MissingIds {
+                first: expected,
+                last: id - 1,
+            });
+        }
+        expected = id.saturating_add(1);
+    }
+
+    issues
+}
+
+/// Every number written more than once, in the order their second occurrence is written.
+fn repeated(numbers: &[u32]) -> impl Iterator<Item = u32> + '_ {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    numbers
+        .iter()
+        .copied()
+        .filter(move |&number| !seen.insert(number) && reported.insert(number))
+}
@@ -0,0 +1,62 @@
+use aoc_core::Solution;
+use day4::{Card, Day4, OverflowError};
+
+#[test]
+fn points_double_with_every_pair() {
+    let card = Card::new(
+        1,
+        vec![41, 48, 83, 86, 17],
+        vec![83, 86, 6, 31, 17, 9, 48, 53],
+    );
+
+    assert_eq!(card.pairs(), 4);
+    assert_eq!(card.points(), Ok(8));
+    assert!(card.is_winning(17));
+    assert!(!card.is_winning(9));
+
+    let card = Card::new(2, vec![1, 2], vec![3, 4]);
+    assert_eq!((card.pairs(), card.points()), (0, Ok(0)));
+}
+
+#[test]
+fn large_winning_numbers_are_matched() {
+    let card = Card::new(1, vec![4_000_000_000, 7], vec![4_000_000_000, 7, 8]);
+
+    assert_eq!(card.pairs(), 2);
+    assert!(!card.is_winning(u32::MAX));
+}
+
+#[test]
+fn sparse_and_dense_winning_numbers_are_matched() {
+    let card = Card::new(1, vec![1_000_000], vec![5, 1_000_000]);
+    assert_eq!(card.pairs(), 1);
+    assert!(!card.is_winning(5));
+
+    let winning: Vec<u32> = (0..640).step_by(64).collect();
+    let card = Card::new(2, winning.clone(), (0..700).collect());
+    assert_eq!(card.pairs(), 10);
+    assert!(card.is_winning(576));
+    assert!(!card.is_winning(640));
+}
+
+#[test]
+fn cards_keep_their_numbers() {
+    let table = Day4::default().parse("Card 3:  1 21 | 21  1 14\n").unwrap();
+    let cards = table.cards();
+
+    assert_eq!(cards[0].id(), 3);
+    assert_eq!(cards[0].winning_numbers(), [1, 21]);
+    assert_eq!(cards[0].numbers(), [21, 1, 14]);
+    assert_eq!(cards[0].points(), Ok(2));
+}
+
+#[test]
+fn overflowing_points_name_their_card() {
+    let numbers: Vec<u32> = (1..=64).collect();
+    let card = Card::new(7, numbers.clone(), numbers);
+    assert_eq!(card.points(), Ok(1 << 63));
+
+    let numbers: Vec<u32> = (1..=65).collect();
+    let card = Card::new(8, numbers.clone(), numbers);
+    assert_eq!(card.points(), Err(OverflowError::Points(8)));
+}
@@ -0,0 +1,70 @@
+use aoc_core::Solution;
+use day4::{Card, CardExplanation, CardTable, Day4};
+
+const SAMPLE: &str = include_str!("../1_sample.txt");
+
+#[test]
+fn explains_every_card() {
+    let table = Day4::default().parse(SAMPLE).unwrap();
+    let explanations = table.explain().unwrap();
+
+    assert_eq!(explanations.len(), 6);
+    assert_eq!(
+        explanations[0],
+        CardExplanation {
+            id: 1,
+            matches: 4,
+            points: 8,
+            copies: 1,
+            grants: vec![2, 3, 4, 5],
+        }
+    );
+
+    let copies: Vec<u64> = explanations.iter().map(|card| card.copies).collect();
+    assert_eq!(copies, [1, 2, 4, 8, 14, 1]);
+    assert!(explanations[5].grants.is_empty());
+}
+
+#[test]
+fn grants_stop_at_the_last_card() {
+    let table = CardTable::new(vec![
+        Card::new(1, vec![1, 2, 3], vec![1, 2, 3]),
+        Card::new(2, vec![1], vec![1]),
+    ])
+    .unwrap();
+
+    let explanations = table.explain().unwrap();
+    assert_eq!(explanations[0].grants, [2]);
+}
+
+#[test]
+fn explanation_table_aligns_columns() {
+    let table = CardTable::new(vec![
+        Card::new(1, vec![1, 2], vec![1, 2]),
+        Card::new(2, vec![1], vec![1]),
+        Card::new(3, vec![1], vec![2]),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        CardExplanation::table(&table.explain().unwrap()),
+        "Card  Matches  Points  Copies  Grants\n\
+         1     2        2       1       2, 3\n\
+         2     1        1       2       3\n\
+         3     0        0       4       -\n"
+    );
+}
+
+#[test]
+fn dot_links_cards_to_the_copies_they_win() {
+    let table = Day4::default().parse(SAMPLE).unwrap();
+    let dot = table.dot();
+
+    assert!(dot.starts_with("digraph scratchcards {"));
+    assert!(dot.contains("card3 [label=\"Card 4\\n1 matches, 8 copies\"];"));
+    assert!(dot.contains("card0 -> card1 [label=\"1\", weight=1];"));
+    assert!(dot.contains("card2 -> card4 [label=\"4\", weight=4];"));
+    assert!(!dot.contains("card5 ->"));
+    assert_eq!(dot.matches("->").count(), 4 + 2 + 2 + 1);
+    assert!(dot.ends_with("}\n"));
+}
@@ -0,0 +1,56 @@
+use aoc_core::Solution;
+use day4::{Card, CardTable, Day4, OverflowError};
+
+const SAMPLE: &str = include_str!("../1_sample.txt");
+
+#[test]
+fn copies_of_every_card() {
+    let table = Day4::default().parse(SAMPLE).unwrap();
+
+    assert_eq!(table.copies(), [1, 2, 4, 8, 14, 1]);
+    assert_eq!(table.total(), 30);
+
+    let (card, copies) = table.iter().nth(3).unwrap();
+    assert_eq!((card.id(), copies), (4, 8));
+}
+
+#[test]
+fn copies_stop_at_the_last_card() {
+    let table = CardTable::new(vec![
+        Card::new(1, vec![1, 2, 3], vec![1, 2, 3]),
+        Card::new(2, vec![1], vec![1]),
+    ])
+    .unwrap();
+
+    // Card 1 would win copies of cards 2 to 4, but only card 2 exists.
+    assert_eq!(table.copies(), [1, 2]);
+    assert_eq!(table.total(), 3);
+}
+
+#[test]
+fn an_empty_pile_holds_no_cards() {
+    let table = CardTable::new(Vec::new()).unwrap();
+
+    assert!(table.copies().is_empty());
+    assert_eq!(table.total(), 0);
+}
+
+#[test]
+fn overflowing_copies_name_their_card() {
+    // Every card wins a copy of all the cards after it, so each holds twice the copies of the
+    // card before it.
+    let pile = |count: u32| -> Vec<Card> {
+        (1..=count)
+            .map(|id| Card::new(id, vec![1], vec![1; count as usize]))
+            .collect()
+    };
+
+    let table = CardTable::new(pile(64)).unwrap();
+    assert_eq!(table.copies()[63], 1 << 63);
+    assert_eq!(table.total(), u64::MAX);
+
+    assert!(matches!(
+        CardTable::new(pile(65)),
+        Err(OverflowError::Copies(65))
+    ));
+}
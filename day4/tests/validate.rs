@@ -0,0 +1,50 @@
+use aoc_core::Solution;
+use day4::{validate, Card, CardIssue, Day4, Validation, ValidationError};
+
+const INVALID: &str = "Card 1: 1 2 2 | 1 1 3\n\
+                       Card 3: 4 | 4\n\
+                       Card 2: 5 | 6\n\
+                       Card 3: 7 | 7\n\
+                       Card 6: 1 | 2\n";
+
+#[test]
+fn reports_every_kind_of_issue() {
+    let table = Day4::default().parse(INVALID).unwrap();
+
+    assert_eq!(
+        table.issues(),
+        [
+            CardIssue::RepeatedWinningNumber { id: 1, number: 2 },
+            CardIssue::RepeatedNumber { id: 1, number: 1 },
+            CardIssue::OutOfOrder { previous: 3, id: 2 },
+            CardIssue::DuplicateId { id: 3, position: 4 },
+            CardIssue::MissingIds { first: 4, last: 5 },
+        ]
+    );
+}
+
+#[test]
+fn well_formed_piles_have_no_issues() {
+    let table = Day4::default()
+        .parse(include_str!("../1_sample.txt"))
+        .unwrap();
+    assert!(table.issues().is_empty());
+
+    let late_start = [Card::new(2, vec![1], vec![1])];
+    assert_eq!(
+        validate(&late_start),
+        [CardIssue::MissingIds { first: 1, last: 1 }]
+    );
+}
+
+#[test]
+fn denying_issues_refuses_the_pile() {
+    let day = Day4 {
+        validation: Validation::Deny,
+    };
+
+    let error = day.parse(INVALID).unwrap_err();
+    let issues = &error.downcast_ref::<ValidationError>().unwrap().0;
+    assert_eq!(issues.len(), 5);
+    assert!(day.parse(include_str!("../1_sample.txt")).is_ok());
+}